@@ -1,4 +1,4 @@
-use learn_rust::graph::AdjacencyMatrix;
+use learn_rust::graph::{AdjacencyMatrix, dijkstra};
 
 #[derive(Debug, Clone)]
 struct City {
@@ -69,6 +69,20 @@ fn main() {
         }
     }
     
+    println!("\n最短路线:");
+    // 以道路距离作为权重计算从北京出发的最短路线
+    let routes = dijkstra(&city_graph, 0, city_graph.vertices(), |road| road.distance);
+    for target in 1..city_graph.vertices() {
+        if let (Some(path), Some(distance)) = (routes.path_to(target), routes.distance_to(target)) {
+            let names: Vec<&str> = path
+                .iter()
+                .filter_map(|&v| city_graph.get_vertex_data(v))
+                .map(|city| city.name.as_str())
+                .collect();
+            println!("  {}: {:.1} 公里", names.join(" -> "), distance);
+        }
+    }
+
     println!("\n=== 整数权重图示例 ===");
     
     // 创建一个简单的整数权重图
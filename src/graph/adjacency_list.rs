@@ -1,9 +1,13 @@
 //! 邻接表存储结构
 
-use super::traversal::GraphNeighbor;
+use super::graph_trait::Graph;
+use super::traversal::{EdgeWeight, GraphNeighbor};
 
 /// 图的邻接表存储结构
 ///
+/// 用 [`new`](Self::new) 创建有向图，用 [`new_undirected`](Self::new_undirected) 创建无向图。
+/// 无向图中每条边 `(u, v)` 同时出现在 `u` 和 `v` 的邻接表中（自环只出现一次），边数只计一次。
+///
 /// # 类型参数
 /// * `T` - 顶点存储的数据类型
 /// * `W` - 边的权重类型
@@ -11,8 +15,10 @@ use super::traversal::GraphNeighbor;
 pub struct AdjacencyList<T, W> {
     /// 顶点数量
     vertices: usize,
-    /// 边的数量
+    /// 边的数量，无向边只计一次
     edges: usize,
+    /// 是否为有向图
+    directed: bool,
     /// 顶点数据
     vertex_data: Vec<Option<T>>,
     /// 邻接表，使用向量的向量存储，内部存储 (目标顶点, 权重)
@@ -23,7 +29,7 @@ impl<T, W> AdjacencyList<T, W>
 where
     W: Clone + PartialEq,
 {
-    /// 创建一个新的邻接表
+    /// 创建一个新的有向图邻接表
     ///
     /// # 参数
     /// * `vertices` - 顶点数量
@@ -41,17 +47,34 @@ where
         AdjacencyList {
             vertices,
             edges: 0,
+            directed: true,
             vertex_data,
             adj,
         }
     }
 
+    /// 创建一个新的无向图邻接表
+    ///
+    /// # 参数
+    /// * `vertices` - 顶点数量
+    pub fn new_undirected(vertices: usize) -> Self {
+        AdjacencyList {
+            directed: false,
+            ..Self::new(vertices)
+        }
+    }
+
+    /// 是否为有向图
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
     /// 获取顶点数量
     pub fn vertices(&self) -> usize {
         self.vertices
     }
 
-    /// 获取边的数量
+    /// 获取边的数量，无向图中每条边只计一次
     pub fn edges(&self) -> usize {
         self.edges
     }
@@ -72,7 +95,56 @@ where
         self.vertex_data[vertex].as_ref()
     }
 
-    /// 添加一条边
+    /// 添加一个顶点
+    ///
+    /// # 参数
+    /// * `data` - 要存储的数据
+    ///
+    /// # 返回值
+    /// 返回新顶点的索引
+    pub fn add_vertex(&mut self, data: T) -> usize {
+        self.adj.push(Vec::new());
+        self.vertex_data.push(Some(data));
+        self.vertices += 1;
+        self.vertices - 1
+    }
+
+    /// 删除一个顶点，并从所有邻接表中删除依附于它的边
+    ///
+    /// 顶点下标保持连续：下标大于 `vertex` 的顶点依次前移一位，邻接表中记录的目标顶点随之更新。
+    ///
+    /// # 参数
+    /// * `vertex` - 顶点索引
+    ///
+    /// # 返回值
+    /// 返回被删除顶点的数据，如果顶点没有数据则返回None
+    ///
+    /// # Panics
+    /// 当顶点索引超出范围时会panic
+    pub fn remove_vertex(&mut self, vertex: usize) -> Option<T> {
+        if vertex >= self.vertices {
+            panic!("Vertex index out of bounds");
+        }
+
+        // 无向图中每条依附于该顶点的边都在它自己的邻接表中出现一次
+        self.edges -= self.adj.remove(vertex).len();
+        for entries in &mut self.adj {
+            let before = entries.len();
+            entries.retain(|(to, _)| *to != vertex);
+            if self.directed {
+                self.edges -= before - entries.len();
+            }
+            for (to, _) in entries.iter_mut() {
+                if *to > vertex {
+                    *to -= 1;
+                }
+            }
+        }
+        self.vertices -= 1;
+        self.vertex_data.remove(vertex)
+    }
+
+    /// 添加一条边，无向图中同时添加到两个端点的邻接表
     ///
     /// # 参数
     /// * `from` - 起始顶点
@@ -83,14 +155,23 @@ where
             panic!("Vertex index out of bounds");
         }
 
-        // 检查边是否已存在，如果存在则更新权重
-        if let Some(edge) = self.adj[from].iter_mut().find(|(v, _)| *v == to) {
-            edge.1 = weight;
-            return;
+        if !self.directed && from != to {
+            Self::insert_entry(&mut self.adj[to], from, weight.clone());
         }
+        // 边已存在时只更新权重
+        if Self::insert_entry(&mut self.adj[from], to, weight) {
+            self.edges += 1;
+        }
+    }
 
-        self.adj[from].push((to, weight));
-        self.edges += 1;
+    /// 在一个顶点的邻接表中插入或更新一项，返回是否为新插入的项
+    fn insert_entry(entries: &mut Vec<(usize, W)>, to: usize, weight: W) -> bool {
+        if let Some(edge) = entries.iter_mut().find(|(v, _)| *v == to) {
+            edge.1 = weight;
+            return false;
+        }
+        entries.push((to, weight));
+        true
     }
 
     /// 获取两个顶点之间的边的权重
@@ -105,7 +186,7 @@ where
             .map(|(_, w)| w)
     }
 
-    /// 移除两个顶点之间的边
+    /// 移除两个顶点之间的边，无向图中同时从两个端点的邻接表中移除
     pub fn remove_edge(&mut self, from: usize, to: usize) {
         if from >= self.vertices || to >= self.vertices {
             panic!("Vertex index out of bounds");
@@ -114,6 +195,11 @@ where
         if let Some(idx) = self.adj[from].iter().position(|(v, _)| *v == to) {
             self.adj[from].remove(idx);
             self.edges -= 1;
+            if !self.directed
+                && let Some(idx) = self.adj[to].iter().position(|(v, _)| *v == from)
+            {
+                self.adj[to].remove(idx);
+            }
         }
     }
 }
@@ -152,10 +238,67 @@ where
     }
 }
 
+// 为 AdjacencyList 实现 EdgeWeight trait
+impl<T, W> EdgeWeight for AdjacencyList<T, W>
+where
+    W: Clone + PartialEq,
+{
+    type Weight = W;
+
+    fn edge_weight(&self, from: usize, to: usize) -> Option<&W> {
+        self.get_edge(from, to)
+    }
+}
+
+// 为 AdjacencyList 实现 Graph trait
+impl<T, W> Graph for AdjacencyList<T, W>
+where
+    W: Clone + PartialEq,
+{
+    type VertexData = T;
+
+    fn vertex_count(&self) -> usize {
+        self.vertices
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges
+    }
+
+    fn add_vertex(&mut self, data: T) -> usize {
+        AdjacencyList::add_vertex(self, data)
+    }
+
+    fn remove_vertex(&mut self, vertex: usize) -> Option<T> {
+        AdjacencyList::remove_vertex(self, vertex)
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        AdjacencyList::add_edge(self, from, to, weight);
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) {
+        AdjacencyList::remove_edge(self, from, to);
+    }
+
+    fn vertex_data(&self, vertex: usize) -> Option<&T> {
+        self.get_vertex_data(vertex)
+    }
+
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, &W)> {
+        if vertex >= self.vertices {
+            panic!("Vertex index out of bounds");
+        }
+        self.adj[vertex].iter().map(|(to, weight)| (*to, weight))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph::traversal::{CollectVisitor, breadth_first_search};
+    use crate::graph::traversal::{
+        CollectVisitor, breadth_first_search, depth_first_search, depth_first_search_iterative,
+    };
 
     #[test]
     fn test_adjacency_list() {
@@ -194,6 +337,69 @@ mod tests {
         assert_eq!(graph.get_edge(1, 2), None);
     }
 
+    #[test]
+    fn test_undirected_adjacency_list() {
+        let mut graph = AdjacencyList::<(), i32>::new_undirected(3);
+        assert!(!graph.is_directed());
+
+        graph.add_edge(0, 1, 5);
+        graph.add_edge(2, 1, 3);
+        graph.add_edge(2, 2, 1);
+        assert_eq!(graph.edges(), 3);
+        assert_eq!(graph.get_edge(1, 0), Some(&5));
+        assert_eq!(graph.get_edge(1, 2), Some(&3));
+        // 自环只出现一次
+        assert_eq!(Graph::neighbors(&graph, 2).count(), 2);
+
+        // 从另一端更新权重，边数不变
+        graph.add_edge(1, 0, 8);
+        assert_eq!(graph.edges(), 3);
+        assert_eq!(graph.get_edge(0, 1), Some(&8));
+
+        // 从另一端移除，两个邻接表都被更新
+        graph.remove_edge(1, 2);
+        assert_eq!(graph.edges(), 2);
+        assert_eq!(graph.get_edge(2, 1), None);
+        assert_eq!(graph.first_neighbor(1), Some(0));
+
+        graph.remove_edge(2, 2);
+        assert_eq!(graph.edges(), 1);
+        assert_eq!(graph.first_neighbor(2), None);
+    }
+
+    #[test]
+    fn test_remove_vertex_adjacency_list() {
+        let mut graph = AdjacencyList::<&str, i32>::new(0);
+        for name in ["A", "B", "C", "D"] {
+            graph.add_vertex(name);
+        }
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(1, 2, 2);
+        graph.add_edge(2, 1, 3);
+        graph.add_edge(1, 1, 4);
+        graph.add_edge(2, 3, 5);
+        graph.add_edge(3, 0, 6);
+
+        // 删除B，C、D前移为1、2
+        assert_eq!(graph.remove_vertex(1), Some("B"));
+        assert_eq!(graph.vertices(), 3);
+        assert_eq!(graph.edges(), 2);
+        assert_eq!(graph.get_vertex_data(2), Some(&"D"));
+        assert_eq!(graph.get_edge(1, 2), Some(&5));
+        assert_eq!(graph.get_edge(2, 0), Some(&6));
+        assert_eq!(graph.first_neighbor(0), None);
+
+        // 无向图中依附于顶点的边只计一次
+        let mut graph = AdjacencyList::<(), i32>::new_undirected(3);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(1, 2, 2);
+        graph.add_edge(1, 1, 3);
+        graph.add_edge(0, 2, 4);
+        assert_eq!(graph.remove_vertex(1), None);
+        assert_eq!(graph.edges(), 1);
+        assert_eq!(graph.get_edge(1, 0), Some(&4));
+    }
+
     #[test]
     fn test_bfs_adjacency_list() {
         // 创建相同的有向图
@@ -223,4 +429,25 @@ mod tests {
         assert!(pos_1 < pos_3);
         assert!(pos_1 < pos_4 || pos_2 < pos_4); // 至少一个第二层顶点在4之前
     }
+
+    #[test]
+    fn test_dfs_adjacency_list() {
+        // 创建相同的有向图
+        let mut graph = AdjacencyList::<String, i32>::new(5);
+
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(0, 2, 1);
+        graph.add_edge(1, 3, 1);
+        graph.add_edge(1, 4, 1);
+        graph.add_edge(2, 4, 1);
+
+        // 邻接表按添加顺序返回邻接顶点
+        let mut visitor = CollectVisitor::default();
+        depth_first_search(&graph, 0, &mut visitor, 5);
+        assert_eq!(visitor.order, vec![0, 1, 3, 4, 2]);
+
+        let mut visitor = CollectVisitor::default();
+        depth_first_search_iterative(&graph, 0, &mut visitor, 5);
+        assert_eq!(visitor.order, vec![0, 1, 3, 4, 2]);
+    }
 }
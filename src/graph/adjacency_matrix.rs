@@ -1,9 +1,13 @@
 //! 邻接矩阵存储结构
 
-use super::traversal::GraphNeighbor;
+use super::graph_trait::Graph;
+use super::traversal::{EdgeWeight, GraphNeighbor};
 
 /// 图的邻接矩阵存储结构
 ///
+/// 用 [`new`](Self::new) 创建有向图，用 [`new_undirected`](Self::new_undirected) 创建无向图。
+/// 无向图中边 `(u, v)` 与 `(v, u)` 是同一条边，矩阵始终保持对称，边数只计一次。
+///
 /// # 类型参数
 /// * `T` - 顶点存储的数据类型
 /// * `W` - 边的权重类型
@@ -11,8 +15,10 @@ use super::traversal::GraphNeighbor;
 pub struct AdjacencyMatrix<T, W> {
     /// 顶点数量
     vertices: usize,
-    /// 边的数量
+    /// 边的数量，无向边只计一次
     edges: usize,
+    /// 是否为有向图
+    directed: bool,
     /// 顶点数据
     vertex_data: Vec<Option<T>>,
     /// 邻接矩阵，使用二维向量存储
@@ -23,7 +29,7 @@ impl<T, W> AdjacencyMatrix<T, W>
 where
     W: Clone,
 {
-    /// 创建一个新的邻接矩阵
+    /// 创建一个新的有向图邻接矩阵
     ///
     /// # 参数
     /// * `vertices` - 顶点数量
@@ -48,17 +54,37 @@ where
         AdjacencyMatrix {
             vertices,
             edges: 0,
+            directed: true,
             vertex_data,
             matrix,
         }
     }
 
+    /// 创建一个新的无向图邻接矩阵
+    ///
+    /// # 参数
+    /// * `vertices` - 顶点数量
+    ///
+    /// # 返回值
+    /// 返回一个新的邻接矩阵实例，添加和移除边时同时修改对称位置
+    pub fn new_undirected(vertices: usize) -> Self {
+        AdjacencyMatrix {
+            directed: false,
+            ..Self::new(vertices)
+        }
+    }
+
+    /// 是否为有向图
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
     /// 获取顶点数量
     pub fn vertices(&self) -> usize {
         self.vertices
     }
 
-    /// 获取边的数量
+    /// 获取边的数量，无向图中每条边只计一次
     pub fn edges(&self) -> usize {
         self.edges
     }
@@ -95,7 +121,60 @@ where
         self.vertex_data[vertex].as_ref()
     }
 
-    /// 在两个顶点之间添加一条边
+    /// 添加一个顶点，矩阵随之扩展一行一列
+    ///
+    /// # 参数
+    /// * `data` - 要存储的数据
+    ///
+    /// # 返回值
+    /// 返回新顶点的索引
+    pub fn add_vertex(&mut self, data: T) -> usize {
+        for row in &mut self.matrix {
+            row.push(None);
+        }
+        self.matrix.push(vec![None; self.vertices + 1]);
+        self.vertex_data.push(Some(data));
+        self.vertices += 1;
+        self.vertices - 1
+    }
+
+    /// 删除一个顶点，矩阵随之删去对应的一行一列
+    ///
+    /// 依附于该顶点的边一并删除。顶点下标保持连续：下标大于 `vertex` 的顶点依次前移一位。
+    ///
+    /// # 参数
+    /// * `vertex` - 顶点索引
+    ///
+    /// # 返回值
+    /// 返回被删除顶点的数据，如果顶点没有数据则返回None
+    ///
+    /// # Panics
+    /// 当顶点索引超出范围时会panic
+    pub fn remove_vertex(&mut self, vertex: usize) -> Option<T> {
+        if vertex >= self.vertices {
+            panic!("Vertex index out of bounds");
+        }
+
+        let out_edges = self.matrix[vertex].iter().filter(|w| w.is_some()).count();
+        // 有向图还要删除指向该顶点的边，自环已在出边中计过
+        let in_edges = if self.directed {
+            (0..self.vertices)
+                .filter(|&from| from != vertex && self.matrix[from][vertex].is_some())
+                .count()
+        } else {
+            0
+        };
+        self.edges -= out_edges + in_edges;
+
+        self.matrix.remove(vertex);
+        for row in &mut self.matrix {
+            row.remove(vertex);
+        }
+        self.vertices -= 1;
+        self.vertex_data.remove(vertex)
+    }
+
+    /// 在两个顶点之间添加一条边，无向图中同时设置对称位置
     ///
     /// # 参数
     /// * `from` - 起始顶点
@@ -115,9 +194,10 @@ where
             self.edges -= 1;
         }
 
+        if !self.directed {
+            self.matrix[to][from] = weight.clone();
+        }
         self.matrix[from][to] = weight;
-        // 如果是无向图，同时设置对称位置
-        // self.matrix[to][from] = weight;
     }
 
     /// 获取两个顶点之间的边的权重
@@ -138,7 +218,7 @@ where
         self.matrix[from][to].as_ref()
     }
 
-    /// 移除两个顶点之间的边
+    /// 移除两个顶点之间的边，无向图中同时清除对称位置
     ///
     /// # 参数
     /// * `from` - 起始顶点
@@ -155,6 +235,9 @@ where
             self.edges -= 1;
         }
         self.matrix[from][to] = None;
+        if !self.directed {
+            self.matrix[to][from] = None;
+        }
     }
 }
 
@@ -192,10 +275,70 @@ where
     }
 }
 
+// 为 AdjacencyMatrix 实现 EdgeWeight trait
+impl<T, W> EdgeWeight for AdjacencyMatrix<T, W>
+where
+    W: Clone,
+{
+    type Weight = W;
+
+    fn edge_weight(&self, from: usize, to: usize) -> Option<&W> {
+        self.get_edge(from, to)
+    }
+}
+
+// 为 AdjacencyMatrix 实现 Graph trait
+impl<T, W> Graph for AdjacencyMatrix<T, W>
+where
+    W: Clone,
+{
+    type VertexData = T;
+
+    fn vertex_count(&self) -> usize {
+        self.vertices
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges
+    }
+
+    fn add_vertex(&mut self, data: T) -> usize {
+        AdjacencyMatrix::add_vertex(self, data)
+    }
+
+    fn remove_vertex(&mut self, vertex: usize) -> Option<T> {
+        AdjacencyMatrix::remove_vertex(self, vertex)
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        AdjacencyMatrix::add_edge(self, from, to, Some(weight));
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) {
+        AdjacencyMatrix::remove_edge(self, from, to);
+    }
+
+    fn vertex_data(&self, vertex: usize) -> Option<&T> {
+        self.get_vertex_data(vertex)
+    }
+
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, &W)> {
+        if vertex >= self.vertices {
+            panic!("Vertex index out of bounds");
+        }
+        self.matrix[vertex]
+            .iter()
+            .enumerate()
+            .filter_map(|(to, weight)| weight.as_ref().map(|w| (to, w)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph::traversal::{CollectVisitor, breadth_first_search};
+    use crate::graph::traversal::{
+        CollectVisitor, breadth_first_search, depth_first_search, depth_first_search_iterative,
+    };
 
     #[test]
     fn test_adjacency_matrix() {
@@ -231,6 +374,65 @@ mod tests {
         assert_eq!(graph.get_edge(1, 2), None);
     }
 
+    #[test]
+    fn test_undirected_adjacency_matrix() {
+        let mut graph = AdjacencyMatrix::<(), i32>::new_undirected(3);
+        assert!(!graph.is_directed());
+
+        graph.add_edge(0, 1, Some(5));
+        graph.add_edge(2, 1, Some(3));
+        graph.add_edge(2, 2, Some(1));
+        assert_eq!(graph.edges(), 3);
+        assert_eq!(graph.get_edge(1, 0), Some(&5));
+        assert_eq!(graph.get_edge(1, 2), Some(&3));
+
+        // 从另一端更新权重，边数不变
+        graph.add_edge(1, 0, Some(8));
+        assert_eq!(graph.edges(), 3);
+        assert_eq!(graph.get_edge(0, 1), Some(&8));
+
+        // 从另一端移除，两个位置都被清除
+        graph.remove_edge(1, 2);
+        assert_eq!(graph.edges(), 2);
+        assert_eq!(graph.get_edge(2, 1), None);
+        assert_eq!(graph.first_neighbor(2), Some(2));
+
+        graph.add_edge(0, 1, None);
+        assert_eq!(graph.edges(), 1);
+        assert_eq!(graph.get_edge(1, 0), None);
+    }
+
+    #[test]
+    fn test_remove_vertex_adjacency_matrix() {
+        let mut graph = AdjacencyMatrix::<&str, i32>::new(0);
+        for name in ["A", "B", "C", "D"] {
+            graph.add_vertex(name);
+        }
+        graph.add_edge(0, 1, Some(1));
+        graph.add_edge(1, 2, Some(2));
+        graph.add_edge(2, 1, Some(3));
+        graph.add_edge(1, 1, Some(4));
+        graph.add_edge(2, 3, Some(5));
+        assert_eq!(graph.edges(), 5);
+
+        // 删除B，C、D前移为1、2
+        assert_eq!(graph.remove_vertex(1), Some("B"));
+        assert_eq!(graph.vertices(), 3);
+        assert_eq!(graph.edges(), 1);
+        assert_eq!(graph.get_vertex_data(1), Some(&"C"));
+        assert_eq!(graph.get_edge(1, 2), Some(&5));
+        assert_eq!(graph.first_neighbor(0), None);
+
+        // 无向图中依附于顶点的边只计一次
+        let mut graph = AdjacencyMatrix::<(), i32>::new_undirected(3);
+        graph.add_edge(0, 1, Some(1));
+        graph.add_edge(1, 2, Some(2));
+        graph.add_edge(1, 1, Some(3));
+        assert_eq!(graph.remove_vertex(1), None);
+        assert_eq!(graph.edges(), 0);
+        assert_eq!(graph.vertices(), 2);
+    }
+
     #[test]
     fn test_bfs_adjacency_matrix() {
         // 创建一个简单的有向图用于测试
@@ -257,6 +459,32 @@ mod tests {
         assert_eq!(visitor.order, vec![0, 1, 2, 3, 4]);
     }
 
+    #[test]
+    fn test_dfs_adjacency_matrix() {
+        // 与BFS测试相同的有向图
+        //     0 → 1 → 3
+        //     ↓   ↓
+        //     2 → 4
+        let mut graph = AdjacencyMatrix::<String, i32>::new(5);
+
+        graph.add_edge(0, 1, Some(1));
+        graph.add_edge(0, 2, Some(1));
+        graph.add_edge(1, 3, Some(1));
+        graph.add_edge(1, 4, Some(1));
+        graph.add_edge(2, 4, Some(1));
+
+        // 递归版本
+        let mut visitor = CollectVisitor::default();
+        depth_first_search(&graph, 0, &mut visitor, 5);
+        // 先沿 0 → 1 → 3 走到底，回溯到1访问4，最后访问2
+        assert_eq!(visitor.order, vec![0, 1, 3, 4, 2]);
+
+        // 显式栈版本的访问顺序应与递归版本一致
+        let mut visitor = CollectVisitor::default();
+        depth_first_search_iterative(&graph, 0, &mut visitor, 5);
+        assert_eq!(visitor.order, vec![0, 1, 3, 4, 2]);
+    }
+
     #[test]
     fn test_graph_neighbor_trait() {
         // 测试 GraphNeighbor trait 的基本功能
@@ -1,5 +1,8 @@
 //! 邻接多重表存储结构 - 用于存储无向图
 
+use super::graph_trait::Graph;
+use super::traversal::{EdgeWeight, GraphNeighbor, next_distinct_neighbor};
+
 /// 邻接多重表的边节点
 #[derive(Debug, Clone)]
 pub struct AMLEdge<W> {
@@ -14,6 +17,26 @@ pub struct AMLEdge<W> {
     pub weight: W,
 }
 
+impl<W> AMLEdge<W> {
+    /// 获取边上 `vertex` 之外的另一个顶点
+    pub fn other(&self, vertex: usize) -> usize {
+        if self.ivex == vertex {
+            self.jvex
+        } else {
+            self.ivex
+        }
+    }
+
+    /// 获取边在 `vertex` 的链表中指向的下一条边
+    fn next_link(&self, vertex: usize) -> Option<usize> {
+        if self.ivex == vertex {
+            self.ilink
+        } else {
+            self.jlink
+        }
+    }
+}
+
 /// 邻接多重表的顶点节点
 #[derive(Debug, Clone)]
 pub struct AMLVertex<T> {
@@ -29,7 +52,7 @@ pub struct AMLVertex<T> {
 pub struct AdjacencyMultilist<T, W> {
     pub vertices: Vec<AMLVertex<T>>,
     pub edges: Vec<Option<AMLEdge<W>>>,
-    pub edge_count: usize,
+    edge_count: usize,
 }
 
 impl<T, W> AdjacencyMultilist<T, W>
@@ -58,6 +81,9 @@ where
     }
 
     /// 添加无向边 (i, j)
+    ///
+    /// # Panics
+    /// 当顶点索引超出范围或 `i == j`（自环）时会panic
     pub fn add_edge(&mut self, i: usize, j: usize, weight: W) {
         if i >= self.vertices.len() || j >= self.vertices.len() {
             panic!("Vertex index out of bounds");
@@ -66,9 +92,9 @@ where
             panic!("Self loops not supported in this simple implementation");
         }
 
-        // 头插法插入
-        let ilink = self.vertices[i].first_edge;
-        let jlink = self.vertices[j].first_edge;
+        // 头插法插入；已有 (i, j) 边时插在其中最前一条的前面，使平行边在两个链表中都保持相邻
+        let (i_prev, ilink) = self.insertion_point(i, j);
+        let (j_prev, jlink) = self.insertion_point(j, i);
 
         let edge = AMLEdge {
             ivex: i,
@@ -81,8 +107,8 @@ where
         self.edges.push(Some(edge));
         let edge_idx = self.edges.len() - 1;
 
-        self.vertices[i].first_edge = Some(edge_idx);
-        self.vertices[j].first_edge = Some(edge_idx);
+        self.link_after(i, i_prev, edge_idx);
+        self.link_after(j, j_prev, edge_idx);
 
         self.edge_count += 1;
     }
@@ -121,6 +147,66 @@ where
         }
     }
 
+    /// 删除一个顶点，并从另一端顶点的链表中摘除依附于它的所有边
+    ///
+    /// 顶点下标保持连续：下标大于 `vertex` 的顶点依次前移一位，边上记录的端点随之更新。
+    /// 边在存储池中的下标不变，被删除的边只留下空槽位。
+    ///
+    /// # 参数
+    /// * `vertex` - 顶点索引
+    ///
+    /// # 返回值
+    /// 返回被删除顶点的数据（与邻接矩阵、邻接表的签名一致），邻接多重表的顶点总有数据，所以总是Some
+    ///
+    /// # Panics
+    /// 当顶点索引超出范围时会panic
+    pub fn remove_vertex(&mut self, vertex: usize) -> Option<T> {
+        if vertex >= self.vertices.len() {
+            panic!("Vertex index out of bounds");
+        }
+
+        let incident: Vec<(usize, usize)> = self
+            .incident_edges(vertex)
+            .map(|(idx, edge)| (idx, edge.other(vertex)))
+            .collect();
+        for (idx, other) in incident {
+            self.remove_edge_from_vertex(other, idx);
+            self.edges[idx] = None;
+            self.edge_count -= 1;
+        }
+
+        for edge in self.edges.iter_mut().flatten() {
+            if edge.ivex > vertex {
+                edge.ivex -= 1;
+            }
+            if edge.jvex > vertex {
+                edge.jvex -= 1;
+            }
+        }
+        Some(self.vertices.remove(vertex).data)
+    }
+
+    // 辅助函数：在 vertex 的链表中为连向 other 的新边找到插入位置，返回 (前驱边, 后继边)
+    fn insertion_point(&self, vertex: usize, other: usize) -> (Option<usize>, Option<usize>) {
+        let mut prev = None;
+        for (idx, edge) in self.incident_edges(vertex) {
+            if edge.other(vertex) == other {
+                return (prev, Some(idx));
+            }
+            prev = Some(idx);
+        }
+        (None, self.vertices[vertex].first_edge)
+    }
+
+    // 辅助函数：让 vertex 链表中的前驱边（没有前驱时为 first_edge）指向新边
+    fn link_after(&mut self, vertex: usize, prev: Option<usize>, edge_idx: usize) {
+        match prev.and_then(|p| self.edges[p].as_mut()) {
+            Some(prev_edge) if prev_edge.ivex == vertex => prev_edge.ilink = Some(edge_idx),
+            Some(prev_edge) => prev_edge.jlink = Some(edge_idx),
+            None => self.vertices[vertex].first_edge = Some(edge_idx),
+        }
+    }
+
     // 辅助函数：从顶点的链表中移除指定边
     fn remove_edge_from_vertex(&mut self, vertex: usize, target_edge_idx: usize) {
         let mut prev = None;
@@ -164,9 +250,134 @@ where
     }
 }
 
+impl<T, W> AdjacencyMultilist<T, W> {
+    /// 遍历依附于顶点的所有边（沿 `first_edge` 以及 `ilink` / `jlink` 链）
+    ///
+    /// 迭代器产出 (边下标, 边)
+    pub fn incident_edges(&self, vertex: usize) -> IncidentEdges<'_, W> {
+        IncidentEdges {
+            edges: &self.edges,
+            vertex,
+            current: self.vertices.get(vertex).and_then(|v| v.first_edge),
+        }
+    }
+
+    /// 获取无向边 (i, j) 的权重，存在平行边时返回最近添加的一条
+    pub fn get_edge(&self, i: usize, j: usize) -> Option<&W> {
+        self.incident_edges(i)
+            .find(|(_, edge)| edge.other(i) == j)
+            .map(|(_, edge)| &edge.weight)
+    }
+
+    /// 获取顶点的度
+    pub fn degree(&self, vertex: usize) -> usize {
+        self.incident_edges(vertex).count()
+    }
+}
+
+/// 依附于顶点的边的迭代器，由 [`AdjacencyMultilist::incident_edges`] 创建
+pub struct IncidentEdges<'a, W> {
+    edges: &'a [Option<AMLEdge<W>>],
+    vertex: usize,
+    current: Option<usize>,
+}
+
+impl<'a, W> Iterator for IncidentEdges<'a, W> {
+    type Item = (usize, &'a AMLEdge<W>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.current?;
+        let edge = self.edges[idx].as_ref()?;
+        self.current = edge.next_link(self.vertex);
+        Some((idx, edge))
+    }
+}
+
+// 为 AdjacencyMultilist 实现 GraphNeighbor trait
+// 沿 first_edge 出发，根据边的哪一端是当前顶点选择 ilink 或 jlink
+impl<T, W> GraphNeighbor for AdjacencyMultilist<T, W> {
+    fn first_neighbor(&self, vertex: usize) -> Option<usize> {
+        self.incident_edges(vertex)
+            .next()
+            .map(|(_, edge)| edge.other(vertex))
+    }
+
+    fn next_neighbor(&self, vertex: usize, current_neighbor: usize) -> Option<usize> {
+        // 平行边连接同一个顶点，只产出一次
+        next_distinct_neighbor(
+            self.incident_edges(vertex)
+                .map(|(_, edge)| edge.other(vertex)),
+            current_neighbor,
+        )
+    }
+}
+
+// 为 AdjacencyMultilist 实现 EdgeWeight trait，(i, j) 与 (j, i) 是同一条边
+impl<T, W> EdgeWeight for AdjacencyMultilist<T, W> {
+    type Weight = W;
+
+    fn edge_weight(&self, from: usize, to: usize) -> Option<&W> {
+        self.get_edge(from, to)
+    }
+
+    fn edge_weights(&self, from: usize, to: usize) -> impl Iterator<Item = &W> {
+        // 平行边在链表中相邻
+        self.incident_edges(from)
+            .skip_while(move |(_, edge)| edge.other(from) != to)
+            .take_while(move |(_, edge)| edge.other(from) == to)
+            .map(|(_, edge)| &edge.weight)
+    }
+}
+
+// 为 AdjacencyMultilist 实现 Graph trait
+impl<T, W> Graph for AdjacencyMultilist<T, W>
+where
+    W: Clone,
+{
+    type VertexData = T;
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    fn add_vertex(&mut self, data: T) -> usize {
+        AdjacencyMultilist::add_vertex(self, data)
+    }
+
+    fn remove_vertex(&mut self, vertex: usize) -> Option<T> {
+        AdjacencyMultilist::remove_vertex(self, vertex)
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        AdjacencyMultilist::add_edge(self, from, to, weight);
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) {
+        AdjacencyMultilist::remove_edge(self, from, to);
+    }
+
+    fn vertex_data(&self, vertex: usize) -> Option<&T> {
+        self.get_vertex_data(vertex)
+    }
+
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, &W)> {
+        if vertex >= self.vertices.len() {
+            panic!("Vertex index out of bounds");
+        }
+        self.incident_edges(vertex)
+            .map(move |(_, edge)| (edge.other(vertex), &edge.weight))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::bipartite::is_bipartite;
+    use crate::graph::traversal::{CollectVisitor, breadth_first_search, depth_first_search};
 
     #[test]
     fn test_adjacency_multilist() {
@@ -183,14 +394,130 @@ mod tests {
         // (A, C) w=3
         aml.add_edge(v0, v2, 3);
 
-        assert_eq!(aml.edge_count, 3);
+        assert_eq!(aml.edge_count(), 3);
 
         // Remove (A, C)
         aml.remove_edge(v0, v2);
-        assert_eq!(aml.edge_count, 2);
+        assert_eq!(aml.edge_count(), 2);
 
         // Remove (B, C)
         aml.remove_edge(v1, v2);
-        assert_eq!(aml.edge_count, 1);
+        assert_eq!(aml.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_incident_edges() {
+        let mut aml = AdjacencyMultilist::<(), i32>::new();
+        for _ in 0..4 {
+            aml.add_vertex(());
+        }
+        aml.add_edge(0, 1, 1);
+        aml.add_edge(1, 2, 2);
+        aml.add_edge(3, 1, 3);
+        aml.add_edge(0, 2, 4);
+
+        // 头插法：后插入的边在前
+        let around_1: Vec<(usize, usize)> = aml
+            .incident_edges(1)
+            .map(|(idx, edge)| (idx, edge.other(1)))
+            .collect();
+        assert_eq!(around_1, vec![(2, 3), (1, 2), (0, 0)]);
+        assert_eq!(aml.degree(0), 2);
+
+        aml.remove_edge(1, 2);
+        assert_eq!(aml.degree(1), 2);
+        assert_eq!(aml.degree(2), 1);
+        assert_eq!(aml.degree(4), 0);
+    }
+
+    #[test]
+    fn test_remove_vertex_adjacency_multilist() {
+        let mut aml = AdjacencyMultilist::<&str, i32>::new();
+        for name in ["A", "B", "C", "D"] {
+            aml.add_vertex(name);
+        }
+        aml.add_edge(0, 1, 1);
+        aml.add_edge(1, 2, 2);
+        aml.add_edge(2, 3, 3);
+        aml.add_edge(3, 1, 4);
+        aml.add_edge(0, 2, 5);
+        aml.add_edge(1, 0, 6);
+
+        // 删除B，C、D前移为1、2
+        assert_eq!(aml.remove_vertex(1), Some("B"));
+        assert_eq!(aml.vertices.len(), 3);
+        assert_eq!(aml.edge_count(), 2);
+        assert_eq!(aml.get_vertex_data(2), Some(&"D"));
+        assert_eq!(aml.get_edge(2, 1), Some(&3));
+        assert_eq!(aml.get_edge(0, 1), Some(&5));
+
+        // 两端的链表都已摘除被删除的边
+        assert_eq!(aml.degree(0), 1);
+        assert_eq!(aml.degree(1), 2);
+        assert_eq!(aml.degree(2), 1);
+
+        aml.remove_edge(1, 2);
+        assert_eq!(aml.degree(2), 0);
+        assert_eq!(aml.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_parallel_edges_adjacency_multilist() {
+        let mut aml = AdjacencyMultilist::<(), i32>::new();
+        for _ in 0..3 {
+            aml.add_vertex(());
+        }
+        aml.add_edge(0, 1, 1);
+        aml.add_edge(0, 2, 2);
+        aml.add_edge(1, 0, 3);
+
+        // 平行边插在已有的边前面，在两端的链表中都相邻
+        let around_0: Vec<usize> = aml
+            .incident_edges(0)
+            .map(|(_, edge)| edge.other(0))
+            .collect();
+        assert_eq!(around_0, vec![2, 1, 1]);
+        assert_eq!(aml.first_neighbor(0), Some(2));
+        assert_eq!(aml.next_neighbor(0, 2), Some(1));
+        assert_eq!(aml.next_neighbor(0, 1), None);
+        assert_eq!(aml.first_neighbor(1), Some(0));
+        assert_eq!(aml.next_neighbor(1, 0), None);
+
+        // 最近添加的平行边排在前面
+        assert_eq!(aml.get_edge(1, 0), Some(&3));
+        aml.remove_edge(0, 1);
+        assert_eq!(aml.get_edge(1, 0), Some(&1));
+        assert_eq!(aml.degree(1), 1);
+    }
+
+    #[test]
+    fn test_traversal_adjacency_multilist() {
+        // 无向图 0-1, 0-2, 1-3, 2-3，另有一条平行边 0-1
+        let mut aml = AdjacencyMultilist::<(), i32>::new();
+        for _ in 0..5 {
+            aml.add_vertex(());
+        }
+        aml.add_edge(2, 3, 1);
+        aml.add_edge(1, 3, 1);
+        aml.add_edge(0, 2, 1);
+        aml.add_edge(1, 0, 1);
+        aml.add_edge(0, 1, 2);
+
+        // 每条边从两端都能访问到
+        assert_eq!(aml.first_neighbor(3), Some(1));
+        assert_eq!(aml.next_neighbor(3, 1), Some(2));
+        assert_eq!(aml.next_neighbor(0, 1), Some(2));
+        assert_eq!(aml.next_neighbor(0, 2), None);
+
+        let mut visitor = CollectVisitor::default();
+        breadth_first_search(&aml, 3, &mut visitor, 5);
+        assert_eq!(visitor.order, vec![3, 1, 2, 0]);
+
+        let mut visitor = CollectVisitor::default();
+        depth_first_search(&aml, 0, &mut visitor, 5);
+        assert_eq!(visitor.order, vec![0, 1, 3, 2]);
+
+        // 四元环是二分图
+        assert!(is_bipartite(&aml, 5));
     }
 }
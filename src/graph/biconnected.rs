@@ -0,0 +1,232 @@
+//! 无向图的割点、桥与点双连通分量
+//!
+//! 基于 Tarjan 的 low-link 方法，在邻接多重表上进行。
+//! 邻接多重表中每条无向边只有一个节点，因此可以直接按边下标给边标记所属的分量，
+//! 并且通过比较边下标（而不是父顶点）跳过树边的反向，平行边也能被正确处理。
+
+use super::adjacency_multilist::{AdjacencyMultilist, IncidentEdges};
+
+/// 割点、桥与点双连通分量的计算结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Biconnectivity {
+    /// 所有割点，按编号升序排列
+    pub articulation_points: Vec<usize>,
+    /// 所有桥对应的边下标，按下标升序排列
+    pub bridges: Vec<usize>,
+    /// 每个边槽位所属的点双连通分量编号，空槽位为 `None`
+    pub edge_component: Vec<Option<usize>>,
+    /// 点双连通分量的个数
+    pub component_count: usize,
+}
+
+impl Biconnectivity {
+    /// 判断顶点是否是割点
+    pub fn is_articulation_point(&self, vertex: usize) -> bool {
+        self.articulation_points.binary_search(&vertex).is_ok()
+    }
+
+    /// 判断下标为 `edge` 的边是否是桥
+    pub fn is_bridge(&self, edge: usize) -> bool {
+        self.bridges.binary_search(&edge).is_ok()
+    }
+
+    /// 获取编号为 `component` 的点双连通分量包含的边下标
+    pub fn component_edges(&self, component: usize) -> Vec<usize> {
+        self.edge_component
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| **c == Some(component))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
+
+/// DFS调用栈中的一帧
+struct Frame<'a, W> {
+    /// 当前顶点
+    vertex: usize,
+    /// 进入当前顶点所经过的树边
+    parent_edge: Option<usize>,
+    /// 尚未检查的依附边
+    edges: IncidentEdges<'a, W>,
+}
+
+/// 求无向图的割点、桥和点双连通分量
+///
+/// 一次深度优先搜索同时完成三项计算，时间复杂度为 O(V + E)。
+/// 搜索使用显式栈实现，不会因为图太深而栈溢出。
+///
+/// # 参数
+/// * `graph` - 邻接多重表存储的无向图
+///
+/// # 返回值
+/// 返回割点、桥以及每条边所属的点双连通分量
+pub fn biconnected_components<T, W>(graph: &AdjacencyMultilist<T, W>) -> Biconnectivity {
+    let n = graph.vertices.len();
+    let mut disc: Vec<Option<usize>> = vec![None; n];
+    let mut low = vec![0; n];
+    let mut is_articulation = vec![false; n];
+    let mut bridges = Vec::new();
+    let mut edge_component = vec![None; graph.edges.len()];
+    let mut component_count = 0;
+    let mut edge_stack = Vec::new();
+    let mut time = 0;
+
+    for root in 0..n {
+        if disc[root].is_some() {
+            continue;
+        }
+        disc[root] = Some(time);
+        low[root] = time;
+        time += 1;
+        let mut root_children = 0;
+        let mut calls = vec![Frame {
+            vertex: root,
+            parent_edge: None,
+            edges: graph.incident_edges(root),
+        }];
+
+        while let Some(frame) = calls.last_mut() {
+            let current = frame.vertex;
+            let parent_edge = frame.parent_edge;
+            let current_disc = disc[current].expect("vertex on the call stack is discovered");
+
+            match frame.edges.find(|&(idx, _)| Some(idx) != parent_edge) {
+                Some((idx, edge)) => {
+                    let next = edge.other(current);
+                    match disc[next] {
+                        None => {
+                            // 树边
+                            edge_stack.push(idx);
+                            disc[next] = Some(time);
+                            low[next] = time;
+                            time += 1;
+                            if current == root {
+                                root_children += 1;
+                            }
+                            calls.push(Frame {
+                                vertex: next,
+                                parent_edge: Some(idx),
+                                edges: graph.incident_edges(next),
+                            });
+                        }
+                        // 指向祖先的回边；指向后代的一侧已经在后代处处理过
+                        Some(next_disc) if next_disc < current_disc => {
+                            edge_stack.push(idx);
+                            low[current] = low[current].min(next_disc);
+                        }
+                        Some(_) => {}
+                    }
+                }
+                None => {
+                    calls.pop();
+                    let Some(parent) = calls.last().map(|f| f.vertex) else {
+                        continue;
+                    };
+                    let tree_edge = parent_edge.expect("non-root vertex has a tree edge");
+                    let parent_disc = disc[parent].expect("parent is discovered");
+                    low[parent] = low[parent].min(low[current]);
+
+                    if low[current] > parent_disc {
+                        bridges.push(tree_edge);
+                    }
+                    // current 的子树无法绕过 parent，栈中 tree_edge 之上的边构成一个分量
+                    if low[current] >= parent_disc {
+                        if parent != root {
+                            is_articulation[parent] = true;
+                        }
+                        loop {
+                            let idx = edge_stack.pop().expect("tree edge is still on the stack");
+                            edge_component[idx] = Some(component_count);
+                            if idx == tree_edge {
+                                break;
+                            }
+                        }
+                        component_count += 1;
+                    }
+                }
+            }
+        }
+
+        // 根顶点当且仅当有两个以上的DFS子树时是割点
+        if root_children >= 2 {
+            is_articulation[root] = true;
+        }
+    }
+
+    bridges.sort_unstable();
+    Biconnectivity {
+        articulation_points: (0..n).filter(|&v| is_articulation[v]).collect(),
+        bridges,
+        edge_component,
+        component_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_biconnected_components() {
+        // 0-1-2 构成三角形，2-3 是桥，3-4-5 构成三角形，5-6 之间有两条平行边，7 是孤立顶点
+        let mut aml = AdjacencyMultilist::<(), i32>::new();
+        for _ in 0..8 {
+            aml.add_vertex(());
+        }
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (5, 6),
+            (5, 6),
+            (0, 7),
+        ];
+        for (i, j) in edges {
+            aml.add_edge(i, j, 1);
+        }
+        // 删除 0-7，留下一个空槽位
+        aml.remove_edge(0, 7);
+
+        let result = biconnected_components(&aml);
+        assert_eq!(result.articulation_points, vec![2, 3, 5]);
+        assert_eq!(result.bridges, vec![3]);
+        assert!(result.is_bridge(3));
+        assert!(!result.is_bridge(7));
+        assert!(result.is_articulation_point(5));
+        assert!(!result.is_articulation_point(0));
+        assert_eq!(result.component_count, 4);
+        assert_eq!(result.edge_component[9], None);
+
+        // 同一个分量中的边
+        let mut groups: Vec<Vec<usize>> = (0..result.component_count)
+            .map(|c| result.component_edges(c))
+            .collect();
+        groups.sort();
+        assert_eq!(
+            groups,
+            vec![vec![0, 1, 2], vec![3], vec![4, 5, 6], vec![7, 8]]
+        );
+    }
+
+    #[test]
+    fn test_path_is_all_bridges() {
+        // 路径 0-1-2-3：每条边都是桥，中间顶点都是割点
+        let mut aml = AdjacencyMultilist::<(), i32>::new();
+        for _ in 0..4 {
+            aml.add_vertex(());
+        }
+        aml.add_edge(1, 2, 1);
+        aml.add_edge(0, 1, 1);
+        aml.add_edge(2, 3, 1);
+
+        let result = biconnected_components(&aml);
+        assert_eq!(result.articulation_points, vec![1, 2]);
+        assert_eq!(result.bridges, vec![0, 1, 2]);
+        assert_eq!(result.component_count, 3);
+    }
+}
@@ -0,0 +1,245 @@
+//! 二分图判定
+//!
+//! 用广度优先搜索对顶点交替染色。BFS树中的树边总是连接不同颜色的顶点，
+//! 一旦出现连接同色顶点的非树边，它和两端到最近公共祖先的树路径就构成一个奇环。
+
+use std::error::Error;
+use std::fmt;
+
+use super::traversal::{
+    GraphNeighbor, TraversalControl, TraversalVisitor, breadth_first_visit_all,
+};
+
+/// 图中存在奇环，不是二分图
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OddCycle {
+    /// 环上的顶点，相邻顶点之间（以及最后一个顶点与第一个顶点之间）都有边，长度为奇数
+    pub cycle: Vec<usize>,
+}
+
+impl fmt::Display for OddCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains an odd cycle: {:?}", self.cycle)
+    }
+}
+
+impl Error for OddCycle {}
+
+/// 二分图的一种合法2-染色
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bipartition {
+    /// 每个顶点所在的一侧，`false` 为左侧，`true` 为右侧
+    pub side: Vec<bool>,
+}
+
+impl Bipartition {
+    /// 获取左侧的所有顶点
+    pub fn left(&self) -> Vec<usize> {
+        (0..self.side.len()).filter(|&v| !self.side[v]).collect()
+    }
+
+    /// 获取右侧的所有顶点
+    pub fn right(&self) -> Vec<usize> {
+        (0..self.side.len()).filter(|&v| self.side[v]).collect()
+    }
+}
+
+/// 交替染色的访问器：记录BFS树和颜色，遇到同色的非树边时记录并终止
+struct ColoringVisitor {
+    /// BFS树中的父顶点
+    parent: Vec<Option<usize>>,
+    /// 每个顶点的颜色，每个分量的根顶点为 `false`
+    side: Vec<bool>,
+    /// 连接同色顶点的边
+    conflict: Option<(usize, usize)>,
+}
+
+impl ColoringVisitor {
+    fn check(&mut self, from: usize, to: usize) {
+        if self.conflict.is_none() && self.side[from] == self.side[to] {
+            self.conflict = Some((from, to));
+        }
+    }
+
+    /// 用同色边 `u - v` 和树路径还原出奇环
+    fn odd_cycle(&self, u: usize, v: usize) -> Vec<usize> {
+        let mut on_u_path = vec![false; self.parent.len()];
+        let mut current = Some(u);
+        while let Some(vertex) = current {
+            on_u_path[vertex] = true;
+            current = self.parent[vertex];
+        }
+
+        // v 向上走到 u 的路径上的第一个顶点即为最近公共祖先
+        let mut v_path = Vec::new();
+        let mut lca = v;
+        while !on_u_path[lca] {
+            v_path.push(lca);
+            lca = self.parent[lca].expect("u and v are in the same BFS tree");
+        }
+
+        // lca → ... → u，再经边 u - v 沿 v → ... 回到 lca
+        let mut cycle = vec![u];
+        let mut current = u;
+        while current != lca {
+            current = self.parent[current].expect("lca is an ancestor of u");
+            cycle.push(current);
+        }
+        cycle.reverse();
+        cycle.extend(v_path);
+        cycle
+    }
+}
+
+impl TraversalVisitor for ColoringVisitor {
+    fn examine_edge(&mut self, _from: usize, _to: usize) -> TraversalControl {
+        if self.conflict.is_some() {
+            TraversalControl::Stop
+        } else {
+            TraversalControl::Continue
+        }
+    }
+
+    fn tree_edge(&mut self, from: usize, to: usize) {
+        self.parent[to] = Some(from);
+        self.side[to] = !self.side[from];
+    }
+
+    // 广度优先搜索把所有非树边都报告为前向边或横叉边
+    fn forward_or_cross_edge(&mut self, from: usize, to: usize) {
+        self.check(from, to);
+    }
+}
+
+/// 判断无向图是否是二分图
+///
+/// 对每个连通分量做一次BFS交替染色，每条边只检查常数次，时间复杂度为 O(V + E)
+/// （不计 `next_neighbor` 本身的查找代价）。
+/// 图应当是无向图，即每条边在两个方向上都存储。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 是二分图时返回一种2-染色；否则返回一个奇环作为证明
+pub fn bipartite_coloring<G>(graph: &G, vertex_count: usize) -> Result<Bipartition, OddCycle>
+where
+    G: GraphNeighbor,
+{
+    let mut visitor = ColoringVisitor {
+        parent: vec![None; vertex_count],
+        side: vec![false; vertex_count],
+        conflict: None,
+    };
+    breadth_first_visit_all(graph, &mut visitor, vertex_count);
+
+    match visitor.conflict {
+        Some((u, v)) => Err(OddCycle {
+            cycle: visitor.odd_cycle(u, v),
+        }),
+        None => Ok(Bipartition { side: visitor.side }),
+    }
+}
+
+/// 判断无向图是否是二分图
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `vertex_count` - 图中顶点的总数
+pub fn is_bipartite<G>(graph: &G, vertex_count: usize) -> bool
+where
+    G: GraphNeighbor,
+{
+    bipartite_coloring(graph, vertex_count).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyList;
+
+    /// 构建无向图，每条边双向存储
+    fn undirected(vertex_count: usize, edges: &[(usize, usize)]) -> AdjacencyList<(), i32> {
+        let mut graph = AdjacencyList::new(vertex_count);
+        for &(u, v) in edges {
+            graph.add_edge(u, v, 1);
+            graph.add_edge(v, u, 1);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_bipartite() {
+        // 偶环 0-1-2-3-0，外加独立的边 4-5 和孤立顶点 6
+        let edges = [(0, 1), (1, 2), (2, 3), (3, 0), (4, 5)];
+        let graph = undirected(7, &edges);
+
+        let coloring = bipartite_coloring(&graph, 7).unwrap();
+        for (u, v) in edges {
+            assert_ne!(coloring.side[u], coloring.side[v]);
+        }
+        assert_eq!(coloring.left(), vec![0, 2, 4, 6]);
+        assert_eq!(coloring.right(), vec![1, 3, 5]);
+        assert!(is_bipartite(&graph, 7));
+    }
+
+    #[test]
+    fn test_odd_cycle() {
+        // 偶环 0-1-2-3-0 上加一条弦 0-2，形成三角形；5 元环 4-5-6-7-8-4 在另一个分量
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (0, 2),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 8),
+            (8, 4),
+        ];
+        let graph = undirected(9, &edges);
+
+        let err = bipartite_coloring(&graph, 9).unwrap_err();
+        assert_eq!(err.cycle.len() % 2, 1);
+        for i in 0..err.cycle.len() {
+            let (u, v) = (err.cycle[i], err.cycle[(i + 1) % err.cycle.len()]);
+            assert!(graph.get_edge(u, v).is_some(), "{u} - {v} is not an edge");
+        }
+        assert!(!is_bipartite(&graph, 9));
+
+        // 只保留 5 元环
+        let graph = undirected(9, &edges[5..]);
+        let err = bipartite_coloring(&graph, 9).unwrap_err();
+        let mut sorted = err.cycle.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_bipartite_grid() {
+        // 双向存储的网格图，BFS中的横叉边很多，树也很深
+        let n = 150;
+        let id = |r: usize, c: usize| r * n + c;
+        let mut edges = Vec::new();
+        for r in 0..n {
+            for c in 0..n {
+                if r + 1 < n {
+                    edges.push((id(r, c), id(r + 1, c)));
+                }
+                if c + 1 < n {
+                    edges.push((id(r, c), id(r, c + 1)));
+                }
+            }
+        }
+        let graph = undirected(n * n, &edges);
+
+        let coloring = bipartite_coloring(&graph, n * n).unwrap();
+        for r in 0..n {
+            for c in 0..n {
+                assert_eq!(coloring.side[id(r, c)], (r + c) % 2 == 1);
+            }
+        }
+    }
+}
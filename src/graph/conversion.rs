@@ -0,0 +1,515 @@
+//! 不同图存储结构之间的转换
+//!
+//! 转换保留顶点数据和边的权重，顶点下标保持不变。
+//! 邻接矩阵和邻接表之间的转换保留有向或无向模式，邻接多重表转换得到的邻接矩阵和邻接表是无向的。
+//! 目标结构无法无损表示源图时（例如邻接矩阵不能存储平行边，邻接多重表只能存储无向图），
+//! 使用 `TryFrom` 并返回 [`ConversionError`]。
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use super::adjacency_list::AdjacencyList;
+use super::adjacency_matrix::AdjacencyMatrix;
+use super::adjacency_multilist::AdjacencyMultilist;
+use super::graph_trait::Graph;
+use super::orthogonal_list::OrthogonalList;
+
+/// 图存储结构之间转换失败的原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionError {
+    /// 顶点没有数据，而目标结构要求每个顶点都有数据
+    MissingVertexData { vertex: usize },
+    /// 有向边 `from -> to` 没有方向相反、权重相同的边与之对应，无法转换为无向图
+    AsymmetricEdge { from: usize, to: usize },
+    /// `from` 和 `to` 之间有平行边，而目标结构不支持平行边
+    ParallelEdge { from: usize, to: usize },
+    /// 顶点上有自环，而目标结构不支持自环
+    SelfLoop { vertex: usize },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::MissingVertexData { vertex } => {
+                write!(f, "vertex {vertex} has no data")
+            }
+            ConversionError::AsymmetricEdge { from, to } => {
+                write!(f, "edge {from} -> {to} has no matching reverse edge")
+            }
+            ConversionError::ParallelEdge { from, to } => {
+                write!(f, "parallel edges between {from} and {to}")
+            }
+            ConversionError::SelfLoop { vertex } => {
+                write!(f, "self loop on vertex {vertex}")
+            }
+        }
+    }
+}
+
+impl Error for ConversionError {}
+
+/// 收集图中所有的有向边 (起点, 终点, 权重)，无向图的每条边从两端各收集一次
+fn directed_edges<G>(graph: &G) -> Vec<(usize, usize, G::Weight)>
+where
+    G: Graph,
+    G::Weight: Clone,
+{
+    (0..graph.vertex_count())
+        .flat_map(|from| {
+            graph
+                .neighbors(from)
+                .map(move |(to, weight)| (from, to, weight.clone()))
+        })
+        .collect()
+}
+
+/// 检查边集中没有平行边
+fn check_no_parallel<W>(edges: &[(usize, usize, W)]) -> Result<(), ConversionError> {
+    let mut seen = HashMap::new();
+    for &(from, to, _) in edges {
+        if seen.insert((from, to), ()).is_some() {
+            return Err(ConversionError::ParallelEdge { from, to });
+        }
+    }
+    Ok(())
+}
+
+/// 把有向边集合并为无向边集：每条边都必须有方向相反、权重相同的边与之对应
+///
+/// 返回的无向边满足 `u < v`，按源图中边的顺序排列。
+fn undirected_edges<W>(
+    edges: Vec<(usize, usize, W)>,
+) -> Result<Vec<(usize, usize, W)>, ConversionError>
+where
+    W: PartialEq,
+{
+    check_no_parallel(&edges)?;
+    let weights: HashMap<(usize, usize), &W> = edges
+        .iter()
+        .map(|(from, to, w)| ((*from, *to), w))
+        .collect();
+
+    for (from, to, weight) in &edges {
+        if from == to {
+            return Err(ConversionError::SelfLoop { vertex: *from });
+        }
+        if weights.get(&(*to, *from)) != Some(&weight) {
+            return Err(ConversionError::AsymmetricEdge {
+                from: *from,
+                to: *to,
+            });
+        }
+    }
+    Ok(edges
+        .into_iter()
+        .filter(|(from, to, _)| from < to)
+        .collect())
+}
+
+/// 把可能缺失的顶点数据转换为必须存在的顶点数据
+fn required_data<T>(data: Vec<Option<T>>) -> Result<Vec<T>, ConversionError> {
+    data.into_iter()
+        .enumerate()
+        .map(|(vertex, d)| d.ok_or(ConversionError::MissingVertexData { vertex }))
+        .collect()
+}
+
+fn matrix_data<T: Clone, W: Clone>(graph: &AdjacencyMatrix<T, W>) -> Vec<Option<T>> {
+    (0..graph.vertices())
+        .map(|v| graph.get_vertex_data(v).cloned())
+        .collect()
+}
+
+fn list_data<T: Clone, W: Clone + PartialEq>(graph: &AdjacencyList<T, W>) -> Vec<Option<T>> {
+    (0..graph.vertices())
+        .map(|v| graph.get_vertex_data(v).cloned())
+        .collect()
+}
+
+fn build_matrix<T, W: Clone>(
+    data: Vec<Option<T>>,
+    edges: Vec<(usize, usize, W)>,
+    directed: bool,
+) -> AdjacencyMatrix<T, W> {
+    let mut matrix = if directed {
+        AdjacencyMatrix::new(data.len())
+    } else {
+        AdjacencyMatrix::new_undirected(data.len())
+    };
+    for (vertex, d) in data.into_iter().enumerate() {
+        if let Some(d) = d {
+            matrix.set_vertex_data(vertex, d);
+        }
+    }
+    // 无向图的每条边从两端各收集了一次，只需添加其中一次
+    for (from, to, weight) in edges.into_iter().filter(|(f, t, _)| directed || f <= t) {
+        matrix.add_edge(from, to, Some(weight));
+    }
+    matrix
+}
+
+fn build_list<T, W: Clone + PartialEq>(
+    data: Vec<Option<T>>,
+    edges: Vec<(usize, usize, W)>,
+    directed: bool,
+) -> AdjacencyList<T, W> {
+    let mut list = if directed {
+        AdjacencyList::new(data.len())
+    } else {
+        AdjacencyList::new_undirected(data.len())
+    };
+    for (vertex, d) in data.into_iter().enumerate() {
+        if let Some(d) = d {
+            list.set_vertex_data(vertex, d);
+        }
+    }
+    for (from, to, weight) in edges.into_iter().filter(|(f, t, _)| directed || f <= t) {
+        list.add_edge(from, to, weight);
+    }
+    list
+}
+
+fn build_orthogonal<T, W: Clone>(
+    data: Vec<T>,
+    edges: Vec<(usize, usize, W)>,
+) -> OrthogonalList<T, W> {
+    let mut ol = OrthogonalList::new();
+    for d in data {
+        ol.add_vertex(d);
+    }
+    for (from, to, weight) in edges {
+        ol.add_edge(from, to, weight);
+    }
+    ol
+}
+
+fn build_multilist<T, W: Clone>(
+    data: Vec<T>,
+    edges: Vec<(usize, usize, W)>,
+) -> AdjacencyMultilist<T, W> {
+    let mut aml = AdjacencyMultilist::new();
+    for d in data {
+        aml.add_vertex(d);
+    }
+    for (i, j, weight) in edges {
+        aml.add_edge(i, j, weight);
+    }
+    aml
+}
+
+/// 邻接矩阵转换为邻接表，保留有向或无向模式
+impl<T, W> From<&AdjacencyMatrix<T, W>> for AdjacencyList<T, W>
+where
+    T: Clone,
+    W: Clone + PartialEq,
+{
+    fn from(graph: &AdjacencyMatrix<T, W>) -> Self {
+        build_list(
+            matrix_data(graph),
+            directed_edges(graph),
+            graph.is_directed(),
+        )
+    }
+}
+
+/// 邻接表转换为邻接矩阵，保留有向或无向模式
+impl<T, W> From<&AdjacencyList<T, W>> for AdjacencyMatrix<T, W>
+where
+    T: Clone,
+    W: Clone + PartialEq,
+{
+    fn from(graph: &AdjacencyList<T, W>) -> Self {
+        build_matrix(list_data(graph), directed_edges(graph), graph.is_directed())
+    }
+}
+
+/// 邻接矩阵转换为十字链表，要求每个顶点都有数据
+impl<T, W> TryFrom<&AdjacencyMatrix<T, W>> for OrthogonalList<T, W>
+where
+    T: Clone,
+    W: Clone,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: &AdjacencyMatrix<T, W>) -> Result<Self, Self::Error> {
+        Ok(build_orthogonal(
+            required_data(matrix_data(graph))?,
+            directed_edges(graph),
+        ))
+    }
+}
+
+/// 邻接表转换为十字链表，要求每个顶点都有数据
+impl<T, W> TryFrom<&AdjacencyList<T, W>> for OrthogonalList<T, W>
+where
+    T: Clone,
+    W: Clone + PartialEq,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: &AdjacencyList<T, W>) -> Result<Self, Self::Error> {
+        Ok(build_orthogonal(
+            required_data(list_data(graph))?,
+            directed_edges(graph),
+        ))
+    }
+}
+
+/// 十字链表转换为邻接矩阵，要求没有平行弧
+impl<T, W> TryFrom<&OrthogonalList<T, W>> for AdjacencyMatrix<T, W>
+where
+    T: Clone,
+    W: Clone,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: &OrthogonalList<T, W>) -> Result<Self, Self::Error> {
+        let edges = directed_edges(graph);
+        check_no_parallel(&edges)?;
+        let data = graph
+            .vertices
+            .iter()
+            .map(|v| Some(v.data.clone()))
+            .collect();
+        Ok(build_matrix(data, edges, true))
+    }
+}
+
+/// 十字链表转换为邻接表，要求没有平行弧
+impl<T, W> TryFrom<&OrthogonalList<T, W>> for AdjacencyList<T, W>
+where
+    T: Clone,
+    W: Clone + PartialEq,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: &OrthogonalList<T, W>) -> Result<Self, Self::Error> {
+        let edges = directed_edges(graph);
+        check_no_parallel(&edges)?;
+        let data = graph
+            .vertices
+            .iter()
+            .map(|v| Some(v.data.clone()))
+            .collect();
+        Ok(build_list(data, edges, true))
+    }
+}
+
+/// 邻接矩阵转换为邻接多重表，要求每条边都有权重相同的反向边、没有自环，且每个顶点都有数据
+impl<T, W> TryFrom<&AdjacencyMatrix<T, W>> for AdjacencyMultilist<T, W>
+where
+    T: Clone,
+    W: Clone + PartialEq,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: &AdjacencyMatrix<T, W>) -> Result<Self, Self::Error> {
+        let edges = undirected_edges(directed_edges(graph))?;
+        Ok(build_multilist(required_data(matrix_data(graph))?, edges))
+    }
+}
+
+/// 邻接表转换为邻接多重表，要求每条边都有权重相同的反向边、没有自环，且每个顶点都有数据
+impl<T, W> TryFrom<&AdjacencyList<T, W>> for AdjacencyMultilist<T, W>
+where
+    T: Clone,
+    W: Clone + PartialEq,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: &AdjacencyList<T, W>) -> Result<Self, Self::Error> {
+        let edges = undirected_edges(directed_edges(graph))?;
+        Ok(build_multilist(required_data(list_data(graph))?, edges))
+    }
+}
+
+/// 十字链表转换为邻接多重表，要求每条弧都有权重相同的反向弧，且没有自环和平行弧
+impl<T, W> TryFrom<&OrthogonalList<T, W>> for AdjacencyMultilist<T, W>
+where
+    T: Clone,
+    W: Clone + PartialEq,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: &OrthogonalList<T, W>) -> Result<Self, Self::Error> {
+        let edges = undirected_edges(directed_edges(graph))?;
+        let data = graph.vertices.iter().map(|v| v.data.clone()).collect();
+        Ok(build_multilist(data, edges))
+    }
+}
+
+/// 邻接多重表转换为十字链表，每条无向边变为方向相反的两条弧
+impl<T, W> From<&AdjacencyMultilist<T, W>> for OrthogonalList<T, W>
+where
+    T: Clone,
+    W: Clone,
+{
+    fn from(graph: &AdjacencyMultilist<T, W>) -> Self {
+        let data = graph.vertices.iter().map(|v| v.data.clone()).collect();
+        build_orthogonal(data, directed_edges(graph))
+    }
+}
+
+/// 邻接多重表转换为无向模式的邻接矩阵，要求没有平行边
+impl<T, W> TryFrom<&AdjacencyMultilist<T, W>> for AdjacencyMatrix<T, W>
+where
+    T: Clone,
+    W: Clone,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: &AdjacencyMultilist<T, W>) -> Result<Self, Self::Error> {
+        let edges = directed_edges(graph);
+        check_no_parallel(&edges)?;
+        let data = graph
+            .vertices
+            .iter()
+            .map(|v| Some(v.data.clone()))
+            .collect();
+        Ok(build_matrix(data, edges, false))
+    }
+}
+
+/// 邻接多重表转换为无向模式的邻接表，要求没有平行边
+impl<T, W> TryFrom<&AdjacencyMultilist<T, W>> for AdjacencyList<T, W>
+where
+    T: Clone,
+    W: Clone + PartialEq,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: &AdjacencyMultilist<T, W>) -> Result<Self, Self::Error> {
+        let edges = directed_edges(graph);
+        check_no_parallel(&edges)?;
+        let data = graph
+            .vertices
+            .iter()
+            .map(|v| Some(v.data.clone()))
+            .collect();
+        Ok(build_list(data, edges, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按 (起点, 终点) 排序后的边集，便于比较
+    fn sorted_edges<G>(graph: &G) -> Vec<(usize, usize, i32)>
+    where
+        G: Graph<Weight = i32>,
+    {
+        let mut edges = directed_edges(graph);
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_directed_conversions() {
+        let mut matrix = AdjacencyMatrix::<&str, i32>::new(3);
+        for (v, name) in ["A", "B", "C"].into_iter().enumerate() {
+            matrix.set_vertex_data(v, name);
+        }
+        matrix.add_edge(0, 1, Some(5));
+        matrix.add_edge(1, 2, Some(3));
+        matrix.add_edge(2, 2, Some(1));
+
+        let list = AdjacencyList::from(&matrix);
+        let ol = OrthogonalList::try_from(&list).unwrap();
+        let back = AdjacencyMatrix::try_from(&ol).unwrap();
+
+        let expected = sorted_edges(&matrix);
+        assert_eq!(sorted_edges(&list), expected);
+        assert_eq!(sorted_edges(&ol), expected);
+        assert_eq!(sorted_edges(&back), expected);
+        assert_eq!(AdjacencyMatrix::from(&list).edges(), 3);
+        assert_eq!(ol.get_vertex_data(2), Some(&"C"));
+        assert_eq!(back.get_vertex_data(1), Some(&"B"));
+
+        // 缺少顶点数据时不能转换为十字链表
+        let mut partial = AdjacencyList::<&str, i32>::new(2);
+        partial.set_vertex_data(0, "A");
+        assert_eq!(
+            OrthogonalList::try_from(&partial).unwrap_err(),
+            ConversionError::MissingVertexData { vertex: 1 }
+        );
+
+        // 平行弧不能转换为邻接表
+        let mut parallel = ol.clone();
+        parallel.add_edge(0, 1, 7);
+        assert_eq!(
+            AdjacencyList::try_from(&parallel).unwrap_err(),
+            ConversionError::ParallelEdge { from: 0, to: 1 }
+        );
+    }
+
+    #[test]
+    fn test_undirected_conversions() {
+        // 对称存储的无向图 0 - 1 - 2
+        let mut list = AdjacencyList::<&str, i32>::new(3);
+        for (v, name) in ["A", "B", "C"].into_iter().enumerate() {
+            list.set_vertex_data(v, name);
+        }
+        for (u, v, w) in [(0, 1, 4), (1, 2, 6)] {
+            list.add_edge(u, v, w);
+            list.add_edge(v, u, w);
+        }
+
+        let aml = AdjacencyMultilist::try_from(&list).unwrap();
+        assert_eq!(aml.edge_count(), 2);
+        assert_eq!(aml.get_edge(2, 1), Some(&6));
+
+        let ol = OrthogonalList::from(&aml);
+        assert_eq!(ol.edge_count(), 4);
+        assert_eq!(sorted_edges(&ol), sorted_edges(&list));
+        assert_eq!(
+            sorted_edges(&AdjacencyMultilist::try_from(&ol).unwrap()),
+            sorted_edges(&aml)
+        );
+        let matrix = AdjacencyMatrix::try_from(&aml).unwrap();
+        assert!(!matrix.is_directed());
+        assert_eq!(matrix.edges(), 2);
+        assert_eq!(sorted_edges(&matrix), sorted_edges(&list));
+
+        // 无向模式在矩阵和邻接表之间保留，并可直接转换为邻接多重表
+        let undirected = AdjacencyList::from(&matrix);
+        assert!(!undirected.is_directed());
+        assert_eq!(undirected.edges(), 2);
+        assert_eq!(
+            AdjacencyMultilist::try_from(&undirected)
+                .unwrap()
+                .edge_count(),
+            2
+        );
+
+        // 缺少反向边或反向边的权重不同
+        let mut directed = list.clone();
+        directed.remove_edge(2, 1);
+        assert_eq!(
+            AdjacencyMultilist::try_from(&directed).unwrap_err(),
+            ConversionError::AsymmetricEdge { from: 1, to: 2 }
+        );
+        directed.add_edge(2, 1, 9);
+        assert!(matches!(
+            AdjacencyMultilist::try_from(&directed),
+            Err(ConversionError::AsymmetricEdge { .. })
+        ));
+
+        // 自环
+        let mut looped = list.clone();
+        looped.add_edge(0, 0, 1);
+        assert_eq!(
+            AdjacencyMultilist::try_from(&looped).unwrap_err(),
+            ConversionError::SelfLoop { vertex: 0 }
+        );
+
+        // 平行边不能转换为邻接表
+        let mut parallel = aml.clone();
+        parallel.add_edge(0, 1, 4);
+        assert_eq!(
+            AdjacencyList::try_from(&parallel).unwrap_err(),
+            ConversionError::ParallelEdge { from: 0, to: 1 }
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! AOE网（用边表示活动的网）的关键路径分析
+//!
+//! 顶点表示事件，弧表示活动，弧的权重是活动的持续时间。
+//! 关键路径上的活动没有任何机动时间，任何一个延误都会推迟整个工程的完成时间。
+
+use std::ops::Sub;
+
+use super::orthogonal_list::OrthogonalList;
+use super::topological::{CycleError, topological_sort_orthogonal};
+use super::weight::Weight;
+
+/// 一个活动（即一条弧）的时间参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Activity<D> {
+    /// 活动对应的弧在十字链表弧存储池中的下标
+    pub arc: usize,
+    /// 活动开始的事件（弧尾）
+    pub tail: usize,
+    /// 活动结束的事件（弧头）
+    pub head: usize,
+    /// 活动的持续时间
+    pub duration: D,
+    /// 活动的最早开始时间 e
+    pub earliest_start: D,
+    /// 活动的最迟开始时间 l
+    pub latest_start: D,
+    /// 活动的时间余量 l - e
+    pub slack: D,
+}
+
+impl<D> Activity<D>
+where
+    D: Weight,
+{
+    /// 判断是否是关键活动（时间余量为0）
+    pub fn is_critical(&self) -> bool {
+        self.slack == D::zero()
+    }
+}
+
+/// 关键路径分析的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriticalPath<D> {
+    /// 每个事件的最早发生时间 ve
+    pub event_earliest: Vec<D>,
+    /// 每个事件的最迟发生时间 vl
+    pub event_latest: Vec<D>,
+    /// 所有活动的时间参数，按弧下标排列
+    pub activities: Vec<Activity<D>>,
+    /// 整个工程的最短完成时间
+    pub project_duration: D,
+}
+
+impl<D> CriticalPath<D>
+where
+    D: Weight,
+{
+    /// 获取所有关键活动
+    pub fn critical_activities(&self) -> Vec<&Activity<D>> {
+        self.activities.iter().filter(|a| a.is_critical()).collect()
+    }
+}
+
+/// 计算十字链表存储的AOE网的关键路径
+///
+/// 按拓扑序沿入弧求事件的最早发生时间，再按逆拓扑序沿出弧求最迟发生时间，
+/// 时间复杂度为 O(V + E)。
+///
+/// # 参数
+/// * `graph` - 十字链表存储的AOE网
+/// * `duration_of` - 从弧上存储的数据中取出活动持续时间的闭包
+///
+/// # 返回值
+/// 返回各事件和活动的时间参数；网中有环时返回 [`CycleError`]
+pub fn critical_path<T, W, D, F>(
+    graph: &OrthogonalList<T, W>,
+    duration_of: F,
+) -> Result<CriticalPath<D>, CycleError>
+where
+    D: Weight + Sub<Output = D>,
+    F: Fn(&W) -> D,
+{
+    let order = topological_sort_orthogonal(graph)?;
+    let n = graph.vertices.len();
+
+    // 最早发生时间：所有前驱事件加上活动时间的最大值
+    let mut event_earliest = vec![D::zero(); n];
+    for &v in &order {
+        for (_, arc) in graph.in_arcs(v) {
+            let candidate = event_earliest[arc.tail_vex] + duration_of(&arc.weight);
+            if candidate > event_earliest[v] {
+                event_earliest[v] = candidate;
+            }
+        }
+    }
+
+    let project_duration = event_earliest
+        .iter()
+        .copied()
+        .fold(D::zero(), |max, t| if t > max { t } else { max });
+
+    // 最迟发生时间：所有后继事件减去活动时间的最小值，汇点为工程完成时间
+    let mut event_latest = vec![project_duration; n];
+    for &v in order.iter().rev() {
+        for (_, arc) in graph.out_arcs(v) {
+            let candidate = event_latest[arc.head_vex] - duration_of(&arc.weight);
+            if candidate < event_latest[v] {
+                event_latest[v] = candidate;
+            }
+        }
+    }
+
+    let activities = graph
+        .arcs
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, slot)| slot.as_ref().map(|arc| (idx, arc)))
+        .map(|(idx, arc)| {
+            let duration = duration_of(&arc.weight);
+            let earliest_start = event_earliest[arc.tail_vex];
+            let latest_start = event_latest[arc.head_vex] - duration;
+            Activity {
+                arc: idx,
+                tail: arc.tail_vex,
+                head: arc.head_vex,
+                duration,
+                earliest_start,
+                latest_start,
+                slack: latest_start - earliest_start,
+            }
+        })
+        .collect();
+
+    Ok(CriticalPath {
+        event_earliest,
+        event_latest,
+        activities,
+        project_duration,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_critical_path() {
+        // 经典AOE网 (v0 为源点，v5 为汇点)
+        // a0: v0→v1(3), a1: v0→v2(2), a2: v1→v3(2), a3: v1→v4(3),
+        // a4: v2→v3(4), a5: v2→v5(3), a6: v3→v5(2), a7: v4→v5(1)
+        let mut ol = OrthogonalList::<&str, u32>::new();
+        for name in ["v0", "v1", "v2", "v3", "v4", "v5"] {
+            ol.add_vertex(name);
+        }
+        let arcs = [
+            (0, 1, 3),
+            (0, 2, 2),
+            (1, 3, 2),
+            (1, 4, 3),
+            (2, 3, 4),
+            (2, 5, 3),
+            (3, 5, 2),
+            (4, 5, 1),
+        ];
+        for (u, v, w) in arcs {
+            ol.add_edge(u, v, w);
+        }
+
+        let result = critical_path(&ol, |w| *w).unwrap();
+        assert_eq!(result.project_duration, 8);
+        assert_eq!(result.event_earliest, vec![0, 3, 2, 6, 6, 8]);
+        assert_eq!(result.event_latest, vec![0, 4, 2, 6, 7, 8]);
+
+        // 关键活动: v0→v2, v2→v3, v3→v5
+        let critical: Vec<(usize, usize)> = result
+            .critical_activities()
+            .iter()
+            .map(|a| (a.tail, a.head))
+            .collect();
+        assert_eq!(critical, vec![(0, 2), (2, 3), (3, 5)]);
+
+        // v1→v4 的余量为1
+        let a3 = result
+            .activities
+            .iter()
+            .find(|a| (a.tail, a.head) == (1, 4))
+            .unwrap();
+        assert_eq!((a3.earliest_start, a3.latest_start, a3.slack), (3, 4, 1));
+    }
+
+    #[test]
+    fn test_critical_path_rejects_cycle() {
+        let mut ol = OrthogonalList::<(), f64>::new();
+        for _ in 0..3 {
+            ol.add_vertex(());
+        }
+        ol.add_edge(0, 1, 1.0);
+        ol.add_edge(1, 2, 2.0);
+        ol.add_edge(2, 1, 0.5);
+
+        let err = critical_path(&ol, |w| *w).unwrap_err();
+        assert_eq!(err.cycle.len(), 2);
+    }
+}
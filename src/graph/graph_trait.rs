@@ -0,0 +1,230 @@
+//! 统一的图接口
+//!
+//! 各种存储结构原有的方法签名并不一致（例如邻接矩阵的 `add_edge` 接受 `Option<W>`），
+//! [`Graph`] trait 在它们之上提供一套相同的操作，算法只需针对 `Graph` 编写一次。
+
+use super::traversal::{EdgeWeight, GraphNeighbor};
+
+/// 所有图存储结构共同的接口
+///
+/// 顶点用从0开始的连续下标表示，边的权重类型由 [`EdgeWeight::Weight`] 给出。
+/// 对于无向的存储结构（邻接多重表、压缩对称矩阵，以及无向模式的邻接矩阵和邻接表），
+/// 边 `(u, v)` 与 `(v, u)` 是同一条边。
+pub trait Graph: GraphNeighbor + EdgeWeight {
+    /// 顶点存储的数据类型
+    type VertexData;
+
+    /// 获取顶点数量
+    fn vertex_count(&self) -> usize;
+
+    /// 获取边的数量，无向边只计一次
+    fn edge_count(&self) -> usize;
+
+    /// 添加一个顶点
+    ///
+    /// # 参数
+    /// * `data` - 顶点数据
+    ///
+    /// # 返回值
+    /// 返回新顶点的下标
+    fn add_vertex(&mut self, data: Self::VertexData) -> usize;
+
+    /// 删除一个顶点以及依附于它的所有边
+    ///
+    /// 顶点下标保持连续：下标大于 `vertex` 的顶点依次前移一位，边上记录的端点随之更新，
+    /// 因此删除之前保存的顶点下标可能指向别的顶点。
+    ///
+    /// # 参数
+    /// * `vertex` - 顶点索引
+    ///
+    /// # 返回值
+    /// 返回被删除顶点的数据，如果顶点没有数据则返回None
+    ///
+    /// # Panics
+    /// 当顶点索引超出范围时会panic
+    fn remove_vertex(&mut self, vertex: usize) -> Option<Self::VertexData>;
+
+    /// 添加一条边 `from -> to`
+    ///
+    /// 不支持平行边的存储结构（矩阵、邻接表）在边已存在时更新其权重，
+    /// 链式存储结构（十字链表、邻接多重表）则会再添加一条平行边。
+    ///
+    /// # 参数
+    /// * `from` - 起始顶点
+    /// * `to` - 终止顶点
+    /// * `weight` - 边的权重
+    ///
+    /// # Panics
+    /// 当顶点索引超出范围时会panic；邻接多重表不支持自环，`from == to` 时也会panic
+    fn add_edge(&mut self, from: usize, to: usize, weight: Self::Weight);
+
+    /// 移除一条边 `from -> to`，边不存在时什么也不做
+    ///
+    /// # 参数
+    /// * `from` - 起始顶点
+    /// * `to` - 终止顶点
+    fn remove_edge(&mut self, from: usize, to: usize);
+
+    /// 获取顶点的数据
+    ///
+    /// # 参数
+    /// * `vertex` - 顶点索引
+    ///
+    /// # 返回值
+    /// 返回顶点的数据，如果顶点没有数据则返回None
+    fn vertex_data(&self, vertex: usize) -> Option<&Self::VertexData>;
+
+    /// 遍历顶点的所有出边
+    ///
+    /// # 参数
+    /// * `vertex` - 顶点索引
+    ///
+    /// # 返回值
+    /// 返回产出 (邻接顶点, 边的权重) 的迭代器，平行边各产出一次
+    ///
+    /// # Panics
+    /// 当顶点索引超出范围时会panic
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, &Self::Weight)>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{
+        AdjacencyList, AdjacencyMatrix, AdjacencyMultilist, OrthogonalList, SymmetricMatrix,
+    };
+
+    /// 只依赖 Graph trait 的算法：建一条路径 0 - 1 - 2 - 3，再删掉最后一条边
+    fn build_path<G>(graph: &mut G, data: [G::VertexData; 4])
+    where
+        G: Graph<Weight = i32>,
+    {
+        for d in data {
+            graph.add_vertex(d);
+        }
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(1, 2, 2);
+        graph.add_edge(2, 3, 3);
+        graph.remove_edge(2, 3);
+    }
+
+    /// 只依赖 Graph trait 的算法：删除路径中间的顶点1，剩下孤立的两个点和边 1 - 2（原来的 2 - 3）
+    fn cut_path<G>(graph: &mut G)
+    where
+        G: Graph<Weight = i32>,
+    {
+        graph.add_edge(2, 3, 3);
+        graph.remove_vertex(1);
+        assert_eq!(graph.vertex_count(), 3);
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.neighbors(0).count(), 0);
+        assert_eq!(graph.neighbors(1).collect::<Vec<_>>(), vec![(2, &3)]);
+    }
+
+    /// 只依赖 Graph trait 的算法：所有出边的权重之和
+    fn out_weight<G>(graph: &G) -> i32
+    where
+        G: Graph<Weight = i32>,
+    {
+        (0..graph.vertex_count())
+            .flat_map(|v| graph.neighbors(v))
+            .map(|(_, w)| *w)
+            .sum()
+    }
+
+    /// 对越界顶点调用 `neighbors`，返回 panic 的消息
+    fn neighbors_panic<G>(graph: &G, vertex: usize) -> String
+    where
+        G: Graph<Weight = i32>,
+    {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            graph.neighbors(vertex).count()
+        }));
+        let payload = result.expect_err("neighbors should panic");
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_neighbors_out_of_bounds() {
+        let mut matrix = AdjacencyMatrix::<&str, i32>::new(0);
+        let mut list = AdjacencyList::<&str, i32>::new(0);
+        let mut ol = OrthogonalList::<&str, i32>::new();
+        let mut aml = AdjacencyMultilist::<&str, i32>::new();
+        let mut sym = SymmetricMatrix::<Option<i32>>::new(0);
+        build_path(&mut matrix, ["A", "B", "C", "D"]);
+        build_path(&mut list, ["A", "B", "C", "D"]);
+        build_path(&mut ol, ["A", "B", "C", "D"]);
+        build_path(&mut aml, ["A", "B", "C", "D"]);
+        build_path(&mut sym, [(), (), (), ()]);
+
+        // 所有存储结构对越界顶点给出同样的 panic
+        let expected = "Vertex index out of bounds";
+        assert_eq!(neighbors_panic(&matrix, 4), expected);
+        assert_eq!(neighbors_panic(&list, 4), expected);
+        assert_eq!(neighbors_panic(&ol, 4), expected);
+        assert_eq!(neighbors_panic(&aml, 4), expected);
+        assert_eq!(neighbors_panic(&sym, 4), expected);
+    }
+
+    #[test]
+    fn test_graph_trait_directed() {
+        let mut matrix = AdjacencyMatrix::<&str, i32>::new(0);
+        let mut list = AdjacencyList::<&str, i32>::new(0);
+        let mut ol = OrthogonalList::<&str, i32>::new();
+        build_path(&mut matrix, ["A", "B", "C", "D"]);
+        build_path(&mut list, ["A", "B", "C", "D"]);
+        build_path(&mut ol, ["A", "B", "C", "D"]);
+
+        assert_eq!(Graph::vertex_count(&matrix), 4);
+        assert_eq!(Graph::edge_count(&matrix), 2);
+        assert_eq!(Graph::vertex_data(&matrix, 3), Some(&"D"));
+        assert_eq!(out_weight(&matrix), 3);
+
+        assert_eq!(Graph::vertex_count(&list), 4);
+        assert_eq!(Graph::edge_count(&list), 2);
+        assert_eq!(Graph::vertex_data(&list, 0), Some(&"A"));
+        assert_eq!(out_weight(&list), 3);
+
+        assert_eq!(Graph::vertex_count(&ol), 4);
+        assert_eq!(Graph::edge_count(&ol), 2);
+        assert_eq!(Graph::vertex_data(&ol, 2), Some(&"C"));
+        assert_eq!(out_weight(&ol), 3);
+        assert_eq!(ol.edge_weight(1, 2), Some(&2));
+        assert_eq!(ol.edge_weight(2, 1), None);
+
+        cut_path(&mut matrix);
+        cut_path(&mut list);
+        cut_path(&mut ol);
+        assert_eq!(Graph::vertex_data(&list, 2), Some(&"D"));
+    }
+
+    #[test]
+    fn test_graph_trait_undirected() {
+        let mut aml = AdjacencyMultilist::<&str, i32>::new();
+        let mut sym = SymmetricMatrix::<Option<i32>>::new(0);
+        build_path(&mut aml, ["A", "B", "C", "D"]);
+        build_path(&mut sym, [(), (), (), ()]);
+
+        // 无向边从两端各产出一次
+        assert_eq!(Graph::vertex_count(&aml), 4);
+        assert_eq!(Graph::edge_count(&aml), 2);
+        assert_eq!(Graph::vertex_data(&aml, 1), Some(&"B"));
+        assert_eq!(out_weight(&aml), 6);
+        assert_eq!(aml.edge_weight(2, 1), Some(&2));
+
+        assert_eq!(Graph::vertex_count(&sym), 4);
+        assert_eq!(Graph::edge_count(&sym), 2);
+        assert_eq!(Graph::vertex_data(&sym, 1), Some(&()));
+        assert_eq!(out_weight(&sym), 6);
+        assert_eq!(sym.edge_weight(2, 1), Some(&2));
+        assert_eq!(sym.edge_weight(2, 3), None);
+
+        cut_path(&mut aml);
+        cut_path(&mut sym);
+        assert_eq!(Graph::vertex_data(&aml, 1), Some(&"C"));
+    }
+}
@@ -0,0 +1,372 @@
+//! 二分图匹配（Hopcroft–Karp 算法）与带权指派问题（匈牙利算法）
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::ops::Sub;
+
+use super::adjacency_matrix::AdjacencyMatrix;
+use super::bipartite::Bipartition;
+use super::traversal::{EdgeWeight, GraphNeighbor};
+use super::weight::Weight;
+
+/// 匹配或指派的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matching<D> {
+    /// 匹配的顶点对，Hopcroft–Karp 中为 (左侧顶点, 右侧顶点)，匈牙利算法中为 (行, 列)
+    pub pairs: Vec<(usize, usize)>,
+    /// 匹配边的权重之和
+    pub total_cost: D,
+}
+
+/// 代价矩阵不存在完美指派：某一行无法分配到任何剩余的列
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoPerfectAssignment {
+    /// 无法完成分配的行
+    pub row: usize,
+}
+
+impl fmt::Display for NoPerfectAssignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "no perfect assignment exists: row {} cannot be assigned",
+            self.row
+        )
+    }
+}
+
+impl Error for NoPerfectAssignment {}
+
+/// Hopcroft–Karp 算法的搜索状态
+struct HopcroftKarp<'a, G> {
+    graph: &'a G,
+    /// 每个顶点的划分，`false` 为左侧
+    side: &'a [bool],
+    /// 每个顶点匹配到的顶点
+    mate: Vec<Option<usize>>,
+    /// BFS分层中左侧顶点的层数
+    dist: Vec<Option<usize>>,
+}
+
+impl<G> HopcroftKarp<'_, G>
+where
+    G: GraphNeighbor,
+{
+    /// 获取左侧顶点 `left` 在右侧的所有邻接顶点
+    fn right_neighbors(&self, left: usize) -> Vec<usize> {
+        let mut result = Vec::new();
+        let mut neighbor = self.graph.first_neighbor(left);
+        while let Some(next) = neighbor {
+            if self.side[next] {
+                result.push(next);
+            }
+            neighbor = self.graph.next_neighbor(left, next);
+        }
+        result
+    }
+
+    /// 从所有未匹配的左侧顶点出发分层，返回是否存在增广路径
+    fn bfs(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+        for v in 0..self.side.len() {
+            if !self.side[v] && self.mate[v].is_none() {
+                self.dist[v] = Some(0);
+                queue.push_back(v);
+            } else {
+                self.dist[v] = None;
+            }
+        }
+
+        let mut found = false;
+        while let Some(left) = queue.pop_front() {
+            let next_dist = self.dist[left].map(|d| d + 1);
+            for right in self.right_neighbors(left) {
+                match self.mate[right] {
+                    None => found = true,
+                    Some(partner) if self.dist[partner].is_none() => {
+                        self.dist[partner] = next_dist;
+                        queue.push_back(partner);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        found
+    }
+
+    /// 沿分层图从左侧顶点 `root` 寻找增广路径，找到时翻转路径上的匹配
+    ///
+    /// 使用显式栈代替递归，避免长交错路径导致栈溢出。
+    fn augment(&mut self, root: usize) -> bool {
+        // 栈中保存 (左侧顶点, 它在右侧的邻接顶点, 下一个待检查的下标)
+        let mut stack = vec![(root, self.right_neighbors(root), 0)];
+
+        while let Some((left, rights, next)) = stack.last_mut() {
+            let left = *left;
+            let Some(&right) = rights.get(*next) else {
+                // 走不通的顶点在本阶段不再尝试
+                self.dist[left] = None;
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+
+            match self.mate[right] {
+                None => {
+                    // 找到增广路径：栈中每个左侧顶点与它最后检查的右侧顶点匹配
+                    for (left, rights, next) in stack {
+                        let right = rights[next - 1];
+                        self.mate[left] = Some(right);
+                        self.mate[right] = Some(left);
+                    }
+                    return true;
+                }
+                Some(partner) if self.dist[partner] == self.dist[left].map(|d| d + 1) => {
+                    let partner_rights = self.right_neighbors(partner);
+                    stack.push((partner, partner_rights, 0));
+                }
+                Some(_) => {}
+            }
+        }
+        false
+    }
+}
+
+/// 使用 Hopcroft–Karp 算法求二分图的最大匹配
+///
+/// 每个阶段用BFS从所有未匹配的左侧顶点同时分层，再用DFS找出一组极大的不相交最短增广路径，
+/// 时间复杂度为 O(E·√V)。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor 和 EdgeWeight trait 的图结构，只使用从左侧指向右侧的边
+/// * `partition` - 顶点的左右划分，例如 [`bipartite_coloring`](super::bipartite::bipartite_coloring) 的结果
+/// * `weight_of` - 从边上存储的数据中取出权重的闭包，用于计算匹配边的权重之和
+///
+/// # 返回值
+/// 返回最大匹配的 (左侧顶点, 右侧顶点) 对，按左侧顶点编号排列
+pub fn hopcroft_karp<G, D, F>(graph: &G, partition: &Bipartition, weight_of: F) -> Matching<D>
+where
+    G: GraphNeighbor + EdgeWeight,
+    D: Weight,
+    F: Fn(&G::Weight) -> D,
+{
+    let n = partition.side.len();
+    let mut state = HopcroftKarp {
+        graph,
+        side: &partition.side,
+        mate: vec![None; n],
+        dist: vec![None; n],
+    };
+
+    while state.bfs() {
+        for v in 0..n {
+            if !partition.side[v] && state.mate[v].is_none() {
+                state.augment(v);
+            }
+        }
+    }
+
+    let pairs: Vec<(usize, usize)> = (0..n)
+        .filter(|&v| !partition.side[v])
+        .filter_map(|v| state.mate[v].map(|right| (v, right)))
+        .collect();
+    let total_cost = pairs
+        .iter()
+        .filter_map(|&(left, right)| graph.edge_weight(left, right))
+        .fold(D::zero(), |sum, w| sum + weight_of(w));
+    Matching { pairs, total_cost }
+}
+
+/// 使用匈牙利算法求代价最小的完美指派
+///
+/// 把邻接矩阵看作方阵形式的代价矩阵：第 `i` 行分配给第 `j` 列的代价是边 `i -> j` 的权重，
+/// 没有边的位置表示不允许这样分配。使用行、列势函数的实现，时间复杂度为 O(V³)。
+/// 列势函数会变为负数，因此代价的类型需要是有符号数。
+///
+/// # 参数
+/// * `matrix` - 邻接矩阵形式的代价矩阵
+/// * `cost_of` - 从边上存储的数据中取出代价的闭包
+///
+/// # 返回值
+/// 返回每一行分配到的列 (行, 列)，按行号排列，以及总代价；不存在完美指派时返回 [`NoPerfectAssignment`]
+pub fn hungarian<T, W, D, F>(
+    matrix: &AdjacencyMatrix<T, W>,
+    cost_of: F,
+) -> Result<Matching<D>, NoPerfectAssignment>
+where
+    W: Clone,
+    D: Weight + Sub<Output = D>,
+    F: Fn(&W) -> D,
+{
+    let n = matrix.vertices();
+    let cost = |row: usize, col: usize| matrix.get_edge(row, col).map(&cost_of);
+
+    // 行、列下标从1开始，第0列是每轮新加入的行暂时占用的虚拟列
+    let mut row_potential = vec![D::zero(); n + 1];
+    let mut col_potential = vec![D::zero(); n + 1];
+    // assigned[j] 为分配到第 j 列的行（0表示未分配）
+    let mut assigned = vec![0; n + 1];
+    let mut way = vec![0; n + 1];
+
+    for row in 1..=n {
+        assigned[0] = row;
+        let mut col = 0;
+        // 到每一列的最小约化代价，None 表示不可达
+        let mut min_slack: Vec<Option<D>> = vec![None; n + 1];
+        let mut used = vec![false; n + 1];
+
+        // 沿约化代价为0的边扩展交错树，直到找到一个未分配的列
+        loop {
+            used[col] = true;
+            let current_row = assigned[col];
+            let mut delta: Option<D> = None;
+            let mut next_col = 0;
+
+            for j in 1..=n {
+                if used[j] {
+                    continue;
+                }
+                if let Some(c) = cost(current_row - 1, j - 1) {
+                    let reduced = c - row_potential[current_row] - col_potential[j];
+                    if min_slack[j].is_none_or(|m| reduced < m) {
+                        min_slack[j] = Some(reduced);
+                        way[j] = col;
+                    }
+                }
+                if let Some(slack) = min_slack[j]
+                    && delta.is_none_or(|d| slack < d)
+                {
+                    delta = Some(slack);
+                    next_col = j;
+                }
+            }
+
+            let Some(delta) = delta else {
+                return Err(NoPerfectAssignment { row: row - 1 });
+            };
+
+            // 调整势函数，使树中多出一条约化代价为0的边
+            for j in 0..=n {
+                if used[j] {
+                    row_potential[assigned[j]] = row_potential[assigned[j]] + delta;
+                    col_potential[j] = col_potential[j] - delta;
+                } else if let Some(slack) = min_slack[j] {
+                    min_slack[j] = Some(slack - delta);
+                }
+            }
+
+            col = next_col;
+            if assigned[col] == 0 {
+                break;
+            }
+        }
+
+        // 沿交错路径翻转分配
+        while col != 0 {
+            let previous = way[col];
+            assigned[col] = assigned[previous];
+            col = previous;
+        }
+    }
+
+    let mut pairs: Vec<(usize, usize)> = (1..=n).map(|j| (assigned[j] - 1, j - 1)).collect();
+    pairs.sort_unstable();
+    let total_cost = pairs.iter().fold(D::zero(), |sum, &(row, col)| {
+        sum + cost(row, col).expect("assigned position has a cost")
+    });
+    Ok(Matching { pairs, total_cost })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyList;
+    use crate::graph::bipartite::bipartite_coloring;
+
+    #[test]
+    fn test_hopcroft_karp() {
+        // 工人 0..4，任务 4..8，边上存储完成任务的耗时
+        let edges = [
+            (0, 4, 3),
+            (0, 5, 2),
+            (1, 4, 4),
+            (2, 5, 1),
+            (2, 6, 5),
+            (3, 6, 2),
+            (3, 7, 6),
+        ];
+        let mut graph = AdjacencyList::<(), i32>::new(8);
+        for (u, v, w) in edges {
+            graph.add_edge(u, v, w);
+            graph.add_edge(v, u, w);
+        }
+
+        let partition = bipartite_coloring(&graph, 8).unwrap();
+        assert_eq!(partition.left(), vec![0, 1, 2, 3]);
+
+        let matching = hopcroft_karp(&graph, &partition, |w| *w);
+        assert_eq!(matching.pairs, vec![(0, 5), (1, 4), (2, 6), (3, 7)]);
+        assert_eq!(matching.total_cost, 2 + 4 + 5 + 6);
+
+        // 去掉任务7后只能匹配3对
+        graph.remove_edge(3, 7);
+        graph.remove_edge(7, 3);
+        let matching = hopcroft_karp(&graph, &partition, |w| *w);
+        assert_eq!(matching.pairs.len(), 3);
+    }
+
+    #[test]
+    fn test_hopcroft_karp_long_augmenting_path() {
+        // 路径 l1 - r1 - l2 - r2 - ... - ln - rn，左侧顶点 li 的下标为 n - i，右侧 ri 为 n + i - 1。
+        // 第一阶段 ln, ..., l2 依次匹配 r(n-1), ..., r1，第二阶段只剩一条贯穿整条路径的增广路径
+        let n = 100_000;
+        let left = |i: usize| n - i;
+        let right = |i: usize| n + i - 1;
+        let mut graph = AdjacencyList::<(), i32>::new(2 * n);
+        for i in 1..=n {
+            if i > 1 {
+                graph.add_edge(left(i), right(i - 1), 1);
+            }
+            graph.add_edge(left(i), right(i), 1);
+        }
+        let partition = Bipartition {
+            side: (0..2 * n).map(|v| v >= n).collect(),
+        };
+
+        let matching = hopcroft_karp(&graph, &partition, |w| *w);
+        assert_eq!(matching.pairs.len(), n);
+        assert_eq!(matching.pairs.last(), Some(&(left(1), right(1))));
+        assert_eq!(matching.total_cost, n as i32);
+    }
+
+    #[test]
+    fn test_hungarian() {
+        let costs = [[9, 2, 7, 8], [6, 4, 3, 7], [5, 8, 1, 8], [7, 6, 9, 4]];
+        let mut matrix = AdjacencyMatrix::<(), i32>::new(4);
+        for (i, row) in costs.iter().enumerate() {
+            for (j, &c) in row.iter().enumerate() {
+                matrix.add_edge(i, j, Some(c));
+            }
+        }
+
+        let assignment = hungarian(&matrix, |c| *c).unwrap();
+        assert_eq!(assignment.pairs, vec![(0, 1), (1, 0), (2, 2), (3, 3)]);
+        assert_eq!(assignment.total_cost, 13);
+
+        // 禁止 (2, 2) 后的最优解
+        matrix.remove_edge(2, 2);
+        let assignment = hungarian(&matrix, |c| *c).unwrap();
+        assert_eq!(assignment.total_cost, 14);
+
+        // 第3行和第2行都只能分配到第0列，不存在完美指派
+        for j in 1..4 {
+            matrix.remove_edge(3, j);
+            matrix.remove_edge(2, j);
+        }
+        assert_eq!(
+            hungarian(&matrix, |c| *c).unwrap_err(),
+            NoPerfectAssignment { row: 3 }
+        );
+    }
+}
@@ -0,0 +1,427 @@
+//! 网络最大流（Edmonds–Karp 和 Dinic 算法）与最小割
+//!
+//! 容量网络用十字链表存储，弧的权重通过闭包转换为容量。
+//! 残量网络不单独建图：从顶点出发，沿出弧可以继续增加流量，
+//! 沿入弧可以退回已有的流量，因此反向弧直接由入弧链表提供。
+
+use std::collections::VecDeque;
+use std::ops::Sub;
+
+use super::orthogonal_list::OrthogonalList;
+use super::weight::Weight;
+
+/// 最大流的计算结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxFlow<D> {
+    /// 最大流的流量
+    pub value: D,
+    /// 每个弧槽位上的流量，与 `OrthogonalList::arcs` 一一对应，空槽位为 `None`
+    pub flow: Vec<Option<D>>,
+    /// 最小割中源点一侧的顶点集合 S 的标记，其余顶点属于汇点一侧 T
+    pub source_side: Vec<bool>,
+    /// 最小割中从 S 指向 T 的弧下标，它们的容量之和等于最大流的流量
+    pub cut_arcs: Vec<usize>,
+}
+
+impl<D> MaxFlow<D>
+where
+    D: Weight,
+{
+    /// 获取下标为 `arc` 的弧上的流量
+    pub fn flow_on(&self, arc: usize) -> Option<D> {
+        self.flow.get(arc).copied().flatten()
+    }
+
+    /// 获取最小割中源点一侧的顶点
+    pub fn source_set(&self) -> Vec<usize> {
+        (0..self.source_side.len())
+            .filter(|&v| self.source_side[v])
+            .collect()
+    }
+
+    /// 获取最小割中汇点一侧的顶点
+    pub fn sink_set(&self) -> Vec<usize> {
+        (0..self.source_side.len())
+            .filter(|&v| !self.source_side[v])
+            .collect()
+    }
+}
+
+/// 残量网络中的一步：沿弧正向推进流量，或沿弧反向退回流量
+#[derive(Debug, Clone, Copy)]
+struct Step {
+    /// 弧下标
+    arc: usize,
+    /// 是否沿弧的方向
+    forward: bool,
+}
+
+/// 十字链表上的残量网络
+struct Residual<'a, T, W, D> {
+    graph: &'a OrthogonalList<T, W>,
+    /// 每个弧槽位的容量
+    capacity: Vec<Option<D>>,
+    /// 每个弧槽位上当前的流量
+    flow: Vec<D>,
+    /// 每个顶点在残量网络中可以走的步：出弧正向，入弧反向
+    steps: Vec<Vec<Step>>,
+}
+
+impl<'a, T, W, D> Residual<'a, T, W, D>
+where
+    D: Weight + Sub<Output = D>,
+{
+    fn new<F>(graph: &'a OrthogonalList<T, W>, capacity_of: F) -> Self
+    where
+        F: Fn(&W) -> D,
+    {
+        let capacity = graph
+            .arcs
+            .iter()
+            .map(|slot| slot.as_ref().map(|arc| capacity_of(&arc.weight)))
+            .collect();
+        let steps = (0..graph.vertices.len())
+            .map(|v| {
+                let out = graph
+                    .out_arcs(v)
+                    .map(|(arc, _)| Step { arc, forward: true });
+                let back = graph.in_arcs(v).map(|(arc, _)| Step {
+                    arc,
+                    forward: false,
+                });
+                out.chain(back).collect()
+            })
+            .collect();
+        Residual {
+            graph,
+            capacity,
+            flow: vec![D::zero(); graph.arcs.len()],
+            steps,
+        }
+    }
+
+    /// 沿这一步还能推进的流量
+    fn residual(&self, step: Step) -> D {
+        if step.forward {
+            self.capacity[step.arc].expect("step follows an existing arc") - self.flow[step.arc]
+        } else {
+            self.flow[step.arc]
+        }
+    }
+
+    /// 走这一步到达的顶点：正向到达弧头，反向到达弧尾
+    fn target(&self, step: Step) -> usize {
+        let arc = self.graph.arcs[step.arc]
+            .as_ref()
+            .expect("step follows an existing arc");
+        if step.forward {
+            arc.head_vex
+        } else {
+            arc.tail_vex
+        }
+    }
+
+    /// 沿这一步推进 `amount` 的流量
+    fn push(&mut self, step: Step, amount: D) {
+        if step.forward {
+            self.flow[step.arc] = self.flow[step.arc] + amount;
+        } else {
+            self.flow[step.arc] = self.flow[step.arc] - amount;
+        }
+    }
+
+    /// 沿一条增广路径推进其瓶颈流量，返回推进的流量
+    fn augment(&mut self, path: &[Step]) -> D {
+        let bottleneck = path
+            .iter()
+            .map(|&step| self.residual(step))
+            .reduce(|min, r| if r < min { r } else { min })
+            .expect("augmenting path is not empty");
+        for &step in path {
+            self.push(step, bottleneck);
+        }
+        bottleneck
+    }
+
+    /// 在残量网络上BFS，返回每个顶点到源点的层数（不可达为 `None`）和BFS树中进入该顶点的步
+    fn bfs(&self, source: usize) -> (Vec<Option<usize>>, Vec<Option<Step>>) {
+        let n = self.graph.vertices.len();
+        let mut level = vec![None; n];
+        let mut via = vec![None; n];
+        let mut queue = VecDeque::new();
+        level[source] = Some(0);
+        queue.push_back(source);
+
+        while let Some(current) = queue.pop_front() {
+            let next_level = level[current].map(|l| l + 1);
+            for &step in &self.steps[current] {
+                let next = self.target(step);
+                if level[next].is_none() && self.residual(step) > D::zero() {
+                    level[next] = next_level;
+                    via[next] = Some(step);
+                    queue.push_back(next);
+                }
+            }
+        }
+        (level, via)
+    }
+
+    /// 根据最终的流量构造结果，最小割的 S 侧是残量网络中源点可达的顶点
+    fn finish(self, source: usize, value: D) -> MaxFlow<D> {
+        let (level, _) = self.bfs(source);
+        let source_side: Vec<bool> = level.iter().map(Option::is_some).collect();
+        let cut_arcs = self
+            .graph
+            .arcs
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, slot)| slot.as_ref().map(|arc| (idx, arc)))
+            .filter(|(_, arc)| source_side[arc.tail_vex] && !source_side[arc.head_vex])
+            .map(|(idx, _)| idx)
+            .collect();
+        let flow = self
+            .capacity
+            .iter()
+            .zip(self.flow)
+            .map(|(cap, f)| cap.map(|_| f))
+            .collect();
+        MaxFlow {
+            value,
+            flow,
+            source_side,
+            cut_arcs,
+        }
+    }
+}
+
+/// 检查源点和汇点是否合法
+fn check_terminals<T, W>(graph: &OrthogonalList<T, W>, source: usize, sink: usize) {
+    if source >= graph.vertices.len() || sink >= graph.vertices.len() {
+        panic!("Vertex index out of bounds");
+    }
+    if source == sink {
+        panic!("Source and sink must be different vertices");
+    }
+}
+
+/// 使用 Edmonds–Karp 算法求最大流
+///
+/// 每次沿残量网络中边数最少的增广路径增广，时间复杂度为 O(V·E²)。
+///
+/// # 参数
+/// * `graph` - 十字链表存储的容量网络
+/// * `source` - 源点
+/// * `sink` - 汇点
+/// * `capacity_of` - 从弧上存储的数据中取出容量的闭包，容量不能为负
+///
+/// # 返回值
+/// 返回最大流的流量、每条弧上的流量和最小割
+///
+/// # Panics
+/// 当源点或汇点越界，或者二者相同时 panic
+pub fn edmonds_karp<T, W, D, F>(
+    graph: &OrthogonalList<T, W>,
+    source: usize,
+    sink: usize,
+    capacity_of: F,
+) -> MaxFlow<D>
+where
+    D: Weight + Sub<Output = D>,
+    F: Fn(&W) -> D,
+{
+    check_terminals(graph, source, sink);
+    let mut residual = Residual::new(graph, capacity_of);
+    let mut value = D::zero();
+
+    loop {
+        let (level, via) = residual.bfs(source);
+        if level[sink].is_none() {
+            break;
+        }
+
+        // 沿BFS树从汇点回溯出增广路径
+        let mut path = Vec::new();
+        let mut current = sink;
+        while current != source {
+            let step = via[current].expect("vertex on the BFS tree has an incoming step");
+            let arc = graph.arcs[step.arc]
+                .as_ref()
+                .expect("step follows an existing arc");
+            current = if step.forward {
+                arc.tail_vex
+            } else {
+                arc.head_vex
+            };
+            path.push(step);
+        }
+        path.reverse();
+        value = value + residual.augment(&path);
+    }
+
+    residual.finish(source, value)
+}
+
+/// 使用 Dinic 算法求最大流
+///
+/// 每个阶段先用BFS给残量网络分层，再在层次图上用DFS求阻塞流，
+/// 每个顶点维护当前弧指针，避免重复检查已经走不通的弧。时间复杂度为 O(V²·E)。
+///
+/// # 参数
+/// * `graph` - 十字链表存储的容量网络
+/// * `source` - 源点
+/// * `sink` - 汇点
+/// * `capacity_of` - 从弧上存储的数据中取出容量的闭包，容量不能为负
+///
+/// # 返回值
+/// 返回最大流的流量、每条弧上的流量和最小割
+///
+/// # Panics
+/// 当源点或汇点越界，或者二者相同时 panic
+pub fn dinic<T, W, D, F>(
+    graph: &OrthogonalList<T, W>,
+    source: usize,
+    sink: usize,
+    capacity_of: F,
+) -> MaxFlow<D>
+where
+    D: Weight + Sub<Output = D>,
+    F: Fn(&W) -> D,
+{
+    check_terminals(graph, source, sink);
+    let mut residual = Residual::new(graph, capacity_of);
+    let mut value = D::zero();
+    let n = graph.vertices.len();
+
+    loop {
+        let (level, _) = residual.bfs(source);
+        if level[sink].is_none() {
+            break;
+        }
+
+        // 当前弧指针：每个顶点下一条待检查的步
+        let mut cursor = vec![0; n];
+        // 从源点出发的路径，以及路径上的顶点
+        let mut path: Vec<Step> = Vec::new();
+        let mut vertices = vec![source];
+
+        while let Some(&current) = vertices.last() {
+            if current == sink {
+                value = value + residual.augment(&path);
+                path.clear();
+                vertices.truncate(1);
+                continue;
+            }
+
+            let mut advanced = false;
+            while cursor[current] < residual.steps[current].len() {
+                let step = residual.steps[current][cursor[current]];
+                let next = residual.target(step);
+                if level[next] == level[current].map(|l| l + 1)
+                    && residual.residual(step) > D::zero()
+                {
+                    path.push(step);
+                    vertices.push(next);
+                    advanced = true;
+                    break;
+                }
+                cursor[current] += 1;
+            }
+
+            if !advanced {
+                // 走不通，回退一步并跳过通向这里的步
+                vertices.pop();
+                if path.pop().is_some() {
+                    let previous = *vertices.last().expect("path starts at the source");
+                    cursor[previous] += 1;
+                }
+            }
+        }
+    }
+
+    residual.finish(source, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 经典的6顶点容量网络，最大流为23
+    fn sample_network() -> OrthogonalList<(), u32> {
+        let mut ol = OrthogonalList::new();
+        for _ in 0..6 {
+            ol.add_vertex(());
+        }
+        let arcs = [
+            (0, 1, 16),
+            (0, 2, 13),
+            (1, 2, 10),
+            (2, 1, 4),
+            (1, 3, 12),
+            (3, 2, 9),
+            (2, 4, 14),
+            (4, 3, 7),
+            (3, 5, 20),
+            (4, 5, 4),
+        ];
+        for (u, v, c) in arcs {
+            ol.add_edge(u, v, c);
+        }
+        ol
+    }
+
+    /// 检查容量限制、流量守恒和最小割的容量
+    fn check(ol: &OrthogonalList<(), u32>, result: &MaxFlow<u32>, source: usize, sink: usize) {
+        let mut balance = vec![0i64; ol.vertices.len()];
+        for (idx, slot) in ol.arcs.iter().enumerate() {
+            let Some(arc) = slot else {
+                assert_eq!(result.flow_on(idx), None);
+                continue;
+            };
+            let f = result.flow_on(idx).unwrap();
+            assert!(f <= arc.weight);
+            balance[arc.tail_vex] -= f as i64;
+            balance[arc.head_vex] += f as i64;
+        }
+        for (v, &b) in balance.iter().enumerate() {
+            if v != source && v != sink {
+                assert_eq!(b, 0, "flow is not conserved at {v}");
+            }
+        }
+        assert_eq!(balance[sink], result.value as i64);
+
+        let cut: u32 = result
+            .cut_arcs
+            .iter()
+            .map(|&idx| ol.arcs[idx].as_ref().unwrap().weight)
+            .sum();
+        assert_eq!(cut, result.value);
+        assert!(result.source_side[source]);
+        assert!(!result.source_side[sink]);
+    }
+
+    #[test]
+    fn test_max_flow() {
+        let ol = sample_network();
+        for result in [edmonds_karp(&ol, 0, 5, |c| *c), dinic(&ol, 0, 5, |c| *c)] {
+            assert_eq!(result.value, 23);
+            check(&ol, &result, 0, 5);
+            assert_eq!(result.source_set(), vec![0, 1, 2, 4]);
+            assert_eq!(result.sink_set(), vec![3, 5]);
+        }
+    }
+
+    #[test]
+    fn test_max_flow_disconnected() {
+        // 删除所有进入汇点的弧后流量为0，最小割把汇点单独分开
+        let mut ol = sample_network();
+        ol.remove_edge(3, 5);
+        ol.remove_edge(4, 5);
+
+        for result in [edmonds_karp(&ol, 0, 5, |c| *c), dinic(&ol, 0, 5, |c| *c)] {
+            assert_eq!(result.value, 0);
+            check(&ol, &result, 0, 5);
+            assert!(result.cut_arcs.is_empty());
+            assert_eq!(result.sink_set(), vec![5]);
+        }
+    }
+}
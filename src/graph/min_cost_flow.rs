@@ -0,0 +1,382 @@
+//! 最小费用最大流（带势函数的逐次最短路算法）
+//!
+//! 边上存储的数据通过闭包同时给出容量和单位流量的费用。
+//! 先用 Bellman-Ford 求初始势函数以支持负费用，之后每轮在残量网络上用
+//! Dijkstra 按约化费用求最短增广路径，约化费用始终非负。
+//! 残量网络中反向边的费用为负，因此容量和费用的类型需要是有符号数。
+
+use std::collections::BinaryHeap;
+use std::ops::{Mul, Sub};
+
+use super::orthogonal_list::OrthogonalList;
+use super::shortest_path::{NegativeCycle, extract_cycle};
+use super::traversal::{EdgeWeight, GraphNeighbor};
+use super::weight::{MinScored, Weight};
+
+/// 流网络中的一条边及其上的流量
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowEdge<D> {
+    /// 起点
+    pub from: usize,
+    /// 终点
+    pub to: usize,
+    /// 容量
+    pub capacity: D,
+    /// 单位流量的费用
+    pub cost: D,
+    /// 边上的流量
+    pub flow: D,
+}
+
+/// 最小费用最大流的计算结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinCostFlow<D> {
+    /// 最大流的流量
+    pub value: D,
+    /// 在流量最大的前提下的最小总费用
+    pub total_cost: D,
+    /// 所有边及其上的流量
+    pub edges: Vec<FlowEdge<D>>,
+}
+
+impl<D> MinCostFlow<D>
+where
+    D: Weight,
+{
+    /// 获取从 `from` 到 `to` 的所有边上的流量之和
+    pub fn flow_between(&self, from: usize, to: usize) -> D {
+        self.edges
+            .iter()
+            .filter(|e| e.from == from && e.to == to)
+            .fold(D::zero(), |sum, e| sum + e.flow)
+    }
+}
+
+/// 使用逐次最短路算法求最小费用最大流
+///
+/// 每轮增广一条费用最小的路径，直到汇点不可达。设最大流为 F，
+/// 时间复杂度为 O(VE + F·E·log V)。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor 和 EdgeWeight trait 的有向图
+/// * `vertex_count` - 图中顶点的总数
+/// * `source` - 源点
+/// * `sink` - 汇点
+/// * `capacity_and_cost` - 从边上存储的数据中取出 (容量, 单位费用) 的闭包，容量不能为负
+///
+/// # 返回值
+/// 返回最大流的流量、最小总费用和每条边上的流量，边按起点编号和邻接顺序排列；
+/// 残量网络中存在负费用环时返回 [`NegativeCycle`]
+///
+/// # Panics
+/// 当源点或汇点越界，或者二者相同时 panic
+pub fn min_cost_max_flow<G, D, F>(
+    graph: &G,
+    vertex_count: usize,
+    source: usize,
+    sink: usize,
+    capacity_and_cost: F,
+) -> Result<MinCostFlow<D>, NegativeCycle>
+where
+    G: GraphNeighbor + EdgeWeight,
+    D: Weight + Sub<Output = D> + Mul<Output = D>,
+    F: Fn(&G::Weight) -> (D, D),
+{
+    let mut edges = Vec::new();
+    for vertex in 0..vertex_count {
+        let mut neighbor = graph.first_neighbor(vertex);
+        while let Some(next) = neighbor {
+            for edge in graph.edge_weights(vertex, next) {
+                let (capacity, cost) = capacity_and_cost(edge);
+                edges.push(FlowEdge {
+                    from: vertex,
+                    to: next,
+                    capacity,
+                    cost,
+                    flow: D::zero(),
+                });
+            }
+            neighbor = graph.next_neighbor(vertex, next);
+        }
+    }
+    successive_shortest_paths(vertex_count, edges, source, sink)
+}
+
+/// 在十字链表上求最小费用最大流
+///
+/// 与 [`min_cost_max_flow`] 相同，但保留平行弧，结果中的边按弧下标顺序排列（跳过空槽位）。
+///
+/// # 参数
+/// * `graph` - 十字链表存储的有向图
+/// * `source` - 源点
+/// * `sink` - 汇点
+/// * `capacity_and_cost` - 从弧上存储的数据中取出 (容量, 单位费用) 的闭包，容量不能为负
+///
+/// # 返回值
+/// 返回最大流的流量、最小总费用和每条弧上的流量；残量网络中存在负费用环时返回 [`NegativeCycle`]
+///
+/// # Panics
+/// 当源点或汇点越界，或者二者相同时 panic
+pub fn min_cost_max_flow_orthogonal<T, W, D, F>(
+    graph: &OrthogonalList<T, W>,
+    source: usize,
+    sink: usize,
+    capacity_and_cost: F,
+) -> Result<MinCostFlow<D>, NegativeCycle>
+where
+    D: Weight + Sub<Output = D> + Mul<Output = D>,
+    F: Fn(&W) -> (D, D),
+{
+    let edges = graph
+        .arcs
+        .iter()
+        .flatten()
+        .map(|arc| {
+            let (capacity, cost) = capacity_and_cost(&arc.weight);
+            FlowEdge {
+                from: arc.tail_vex,
+                to: arc.head_vex,
+                capacity,
+                cost,
+                flow: D::zero(),
+            }
+        })
+        .collect();
+    successive_shortest_paths(graph.vertices.len(), edges, source, sink)
+}
+
+/// 残量网络中的一步：(边下标, 是否沿边的方向)
+type Step = (usize, bool);
+
+/// 逐次最短路算法的主体
+fn successive_shortest_paths<D>(
+    vertex_count: usize,
+    mut edges: Vec<FlowEdge<D>>,
+    source: usize,
+    sink: usize,
+) -> Result<MinCostFlow<D>, NegativeCycle>
+where
+    D: Weight + Sub<Output = D> + Mul<Output = D>,
+{
+    if source >= vertex_count || sink >= vertex_count {
+        panic!("Vertex index out of bounds");
+    }
+    if source == sink {
+        panic!("Source and sink must be different vertices");
+    }
+
+    // 每个顶点在残量网络中可以走的步：出边正向，入边反向
+    let mut steps: Vec<Vec<Step>> = vec![Vec::new(); vertex_count];
+    for (idx, edge) in edges.iter().enumerate() {
+        steps[edge.from].push((idx, true));
+        steps[edge.to].push((idx, false));
+    }
+
+    let mut potential = initial_potential(vertex_count, &edges)?;
+    let mut value = D::zero();
+    let mut total_cost = D::zero();
+
+    loop {
+        // 按约化费用 cost(u, v) + p(u) - p(v) 求最短路
+        let mut distances: Vec<Option<D>> = vec![None; vertex_count];
+        let mut via: Vec<Option<Step>> = vec![None; vertex_count];
+        let mut settled = vec![false; vertex_count];
+        let mut heap = BinaryHeap::new();
+        distances[source] = Some(D::zero());
+        heap.push(MinScored {
+            weight: D::zero(),
+            vertex: source,
+        });
+
+        while let Some(MinScored { weight, vertex }) = heap.pop() {
+            if settled[vertex] {
+                continue;
+            }
+            settled[vertex] = true;
+
+            for &(idx, forward) in &steps[vertex] {
+                let edge = &edges[idx];
+                let (next, residual, cost) = if forward {
+                    (edge.to, edge.capacity - edge.flow, edge.cost)
+                } else {
+                    (edge.from, edge.flow, D::zero() - edge.cost)
+                };
+                if residual <= D::zero() || settled[next] {
+                    continue;
+                }
+                let candidate = weight + cost + potential[vertex] - potential[next];
+                let shorter = match distances[next] {
+                    Some(current) => candidate < current,
+                    None => true,
+                };
+                if shorter {
+                    distances[next] = Some(candidate);
+                    via[next] = Some((idx, forward));
+                    heap.push(MinScored {
+                        weight: candidate,
+                        vertex: next,
+                    });
+                }
+            }
+        }
+
+        if distances[sink].is_none() {
+            break;
+        }
+        for (p, d) in potential.iter_mut().zip(&distances) {
+            if let Some(d) = *d {
+                *p = *p + d;
+            }
+        }
+
+        // 沿前驱回溯出增广路径，求瓶颈容量和单位费用
+        let mut path = Vec::new();
+        let mut current = sink;
+        while current != source {
+            let (idx, forward) = via[current].expect("reachable vertex has an incoming step");
+            path.push((idx, forward));
+            current = if forward {
+                edges[idx].from
+            } else {
+                edges[idx].to
+            };
+        }
+        let mut bottleneck = None;
+        let mut unit_cost = D::zero();
+        for &(idx, forward) in &path {
+            let edge = &edges[idx];
+            let (residual, cost) = if forward {
+                (edge.capacity - edge.flow, edge.cost)
+            } else {
+                (edge.flow, D::zero() - edge.cost)
+            };
+            bottleneck = match bottleneck {
+                Some(b) if b <= residual => Some(b),
+                _ => Some(residual),
+            };
+            unit_cost = unit_cost + cost;
+        }
+        let bottleneck = bottleneck.expect("augmenting path is not empty");
+
+        for &(idx, forward) in &path {
+            let edge = &mut edges[idx];
+            edge.flow = if forward {
+                edge.flow + bottleneck
+            } else {
+                edge.flow - bottleneck
+            };
+        }
+        value = value + bottleneck;
+        total_cost = total_cost + unit_cost * bottleneck;
+    }
+
+    Ok(MinCostFlow {
+        value,
+        total_cost,
+        edges,
+    })
+}
+
+/// 用 Bellman-Ford 求初始势函数
+///
+/// 相当于从一个连向所有顶点、费用为0的虚拟源点出发求最短路，只考虑容量为正的边。
+/// 第 V 轮仍能松弛说明存在负费用环。
+fn initial_potential<D>(vertex_count: usize, edges: &[FlowEdge<D>]) -> Result<Vec<D>, NegativeCycle>
+where
+    D: Weight,
+{
+    let mut potential = vec![D::zero(); vertex_count];
+    let mut predecessors = vec![None; vertex_count];
+
+    for round in 0..=vertex_count {
+        let mut changed = None;
+        for edge in edges.iter().filter(|e| e.capacity > D::zero()) {
+            let candidate = potential[edge.from] + edge.cost;
+            if candidate < potential[edge.to] {
+                potential[edge.to] = candidate;
+                predecessors[edge.to] = Some(edge.from);
+                changed = Some(edge.to);
+            }
+        }
+        match changed {
+            None => return Ok(potential),
+            Some(vertex) if round == vertex_count => {
+                return Err(NegativeCycle {
+                    cycle: extract_cycle(&predecessors, vertex, vertex_count),
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(potential)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyList;
+
+    /// (起点, 终点, 容量, 费用)
+    const EDGES: [(usize, usize, i32, i32); 5] = [
+        (0, 1, 2, 1),
+        (0, 2, 1, 2),
+        (1, 2, 1, 1),
+        (1, 3, 1, 3),
+        (2, 3, 2, 1),
+    ];
+
+    #[test]
+    fn test_min_cost_max_flow() {
+        let mut list = AdjacencyList::<(), (i32, i32)>::new(4);
+        let mut ol = OrthogonalList::<(), (i32, i32)>::new();
+        for _ in 0..4 {
+            ol.add_vertex(());
+        }
+        for (u, v, capacity, cost) in EDGES {
+            list.add_edge(u, v, (capacity, cost));
+            ol.add_edge(u, v, (capacity, cost));
+        }
+
+        for result in [
+            min_cost_max_flow(&list, 4, 0, 3, |w| *w).unwrap(),
+            min_cost_max_flow_orthogonal(&ol, 0, 3, |w| *w).unwrap(),
+        ] {
+            assert_eq!(result.value, 3);
+            assert_eq!(result.total_cost, 10);
+            // 这个网络中最大流会占满所有边
+            for (u, v, capacity, _) in EDGES {
+                assert_eq!(result.flow_between(u, v), capacity);
+            }
+            let cost: i32 = result.edges.iter().map(|e| e.flow * e.cost).sum();
+            assert_eq!(cost, result.total_cost);
+        }
+    }
+
+    #[test]
+    fn test_min_cost_flow_negative_costs() {
+        // 两个工人分配两项任务，费用为负表示收益，最优方案 0→2、1→3 总收益 9
+        // 源点 4，汇点 5
+        let mut list = AdjacencyList::<(), (i32, i32)>::new(6);
+        list.add_edge(4, 0, (1, 0));
+        list.add_edge(4, 1, (1, 0));
+        list.add_edge(0, 2, (1, -5));
+        list.add_edge(0, 3, (1, -3));
+        list.add_edge(1, 2, (1, -2));
+        list.add_edge(1, 3, (1, -4));
+        list.add_edge(2, 5, (1, 0));
+        list.add_edge(3, 5, (1, 0));
+
+        let result = min_cost_max_flow(&list, 6, 4, 5, |w| *w).unwrap();
+        assert_eq!(result.value, 2);
+        assert_eq!(result.total_cost, -9);
+        assert_eq!(result.flow_between(0, 2), 1);
+        assert_eq!(result.flow_between(1, 3), 1);
+
+        // 1 → 2 → 1 构成负费用环
+        list.add_edge(2, 1, (1, 1));
+        let err = min_cost_max_flow(&list, 6, 4, 5, |w| *w).unwrap_err();
+        let mut cycle = err.cycle.clone();
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2]);
+    }
+}
@@ -6,22 +6,75 @@
 //! - 对称矩阵 (`SymmetricMatrix`)
 //! - 十字链表 (`OrthogonalList`) - 有向图
 //! - 邻接多重表 (`AdjacencyMultilist`) - 无向图
+//! - 统一的图接口 (`Graph`) 与存储结构之间的转换
 //! - 图遍历相关trait和算法
+//! - 基于迭代器的惰性遍历 (`Bfs`, `Dfs`)
+//! - 最短路径算法 (Dijkstra, Bellman-Ford, Floyd-Warshall)
+//! - 最小生成树算法 (Kruskal, Prim)
+//! - 拓扑排序 (Kahn, DFS) 与关键路径
+//! - 强连通分量 (Tarjan, Kosaraju)
+//! - 割点、桥与点双连通分量
+//! - 二分图判定
+//! - 网络最大流 (Edmonds–Karp, Dinic) 与最小割
+//! - 最小费用最大流
+//! - 二分图最大匹配 (Hopcroft–Karp) 与指派问题 (匈牙利算法)
 
 // 子模块声明
 pub mod adjacency_list;
 pub mod adjacency_matrix;
 pub mod adjacency_multilist;
+pub mod biconnected;
+pub mod bipartite;
+pub mod conversion;
+pub mod critical_path;
+pub mod graph_trait;
+pub mod matching;
+pub mod max_flow;
+pub mod min_cost_flow;
 pub mod orthogonal_list;
+pub mod shortest_path;
+pub mod spanning_tree;
+pub mod strongly_connected;
 pub mod symmetric_matrix;
+pub mod topological;
 pub mod traversal;
+pub mod walker;
+pub mod weight;
 
 // 导出主要类型
 pub use adjacency_list::AdjacencyList;
 pub use adjacency_matrix::AdjacencyMatrix;
-pub use adjacency_multilist::{AMLEdge, AMLVertex, AdjacencyMultilist};
-pub use orthogonal_list::{OLArc, OLVertex, OrthogonalList};
+pub use adjacency_multilist::{AMLEdge, AMLVertex, AdjacencyMultilist, IncidentEdges};
+pub use biconnected::{Biconnectivity, biconnected_components};
+pub use bipartite::{Bipartition, OddCycle, bipartite_coloring, is_bipartite};
+pub use conversion::ConversionError;
+pub use critical_path::{Activity, CriticalPath, critical_path};
+pub use graph_trait::Graph;
+pub use matching::{Matching, NoPerfectAssignment, hopcroft_karp, hungarian};
+pub use max_flow::{MaxFlow, dinic, edmonds_karp};
+pub use min_cost_flow::{FlowEdge, MinCostFlow, min_cost_max_flow, min_cost_max_flow_orthogonal};
+pub use orthogonal_list::{InArcs, OLArc, OLVertex, OrthogonalList, OutArcs};
+pub use shortest_path::{
+    AllPairsShortestPaths, NegativeCycle, ShortestPaths, bellman_ford, dijkstra, floyd_warshall,
+};
+pub use spanning_tree::{
+    MinimumSpanningTree, SpanningEdge, kruskal, kruskal_from_edges, kruskal_multilist, prim,
+    prim_dense,
+};
+pub use strongly_connected::{
+    StronglyConnectedComponents, kosaraju_scc, kosaraju_scc_orthogonal, tarjan_scc,
+};
 pub use symmetric_matrix::SymmetricMatrix;
+pub use topological::{
+    CycleError, topological_sort_dfs, topological_sort_kahn, topological_sort_orthogonal,
+};
 pub use traversal::{
-    CollectVisitor, GraphNeighbor, PrintVisitor, VertexVisitor, breadth_first_search,
+    BfsTree, CollectVisitor, EdgeWeight, GraphInNeighbor, GraphNeighbor, PrintVisitor, Reversed,
+    TraversalControl, TraversalVisitor, VertexVisitor, VisitorAdapter, breadth_first_search,
+    breadth_first_search_multi, breadth_first_tree, breadth_first_tree_multi, breadth_first_visit,
+    breadth_first_visit_all, breadth_first_visit_multi, depth_first_search,
+    depth_first_search_iterative, depth_first_visit, depth_first_visit_all,
+    depth_first_visit_iterative,
 };
+pub use walker::{Bfs, Dfs};
+pub use weight::Weight;
@@ -1,5 +1,8 @@
 //! 十字链表存储结构 - 用于存储有向图
 
+use super::graph_trait::Graph;
+use super::traversal::{EdgeWeight, GraphInNeighbor, GraphNeighbor, next_distinct_neighbor};
+
 /// 十字链表的弧节点
 #[derive(Debug, Clone)]
 pub struct OLArc<W> {
@@ -39,7 +42,7 @@ pub struct OrthogonalList<T, W> {
     /// 空闲弧的链表头，用于重用被删除的位置
     free_arc_head: Option<usize>,
     /// 边的数量
-    pub(crate) edge_count: usize,
+    edge_count: usize,
 }
 
 impl<T, W> OrthogonalList<T, W>
@@ -85,15 +88,26 @@ where
     }
 
     /// 添加一条有向边
+    ///
+    /// 新弧用头插法插入出弧、入弧链表；如果已有 `from -> to` 的弧，则插在其中最前一条的前面，
+    /// 使平行弧在两条链表中都保持相邻。
     pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
         if from >= self.vertices.len() || to >= self.vertices.len() {
             panic!("Vertex index out of bounds");
         }
 
-        // 创建新弧
-        let tail_link = self.vertices[from].first_out;
-        let head_link = self.vertices[to].first_in;
+        let (out_prev, tail_link) =
+            match parallel_position(self.out_arcs(from), |arc| arc.head_vex == to) {
+                Some((prev, idx)) => (prev, Some(idx)),
+                None => (None, self.vertices[from].first_out),
+            };
+        let (in_prev, head_link) =
+            match parallel_position(self.in_arcs(to), |arc| arc.tail_vex == from) {
+                Some((prev, idx)) => (prev, Some(idx)),
+                None => (None, self.vertices[to].first_in),
+            };
 
+        // 创建新弧
         let arc = OLArc {
             tail_vex: from,
             head_vex: to,
@@ -104,9 +118,15 @@ where
 
         let arc_idx = self.alloc_arc(arc);
 
-        // 更新顶点的指针
-        self.vertices[from].first_out = Some(arc_idx);
-        self.vertices[to].first_in = Some(arc_idx);
+        // 更新前驱弧或顶点的指针
+        match out_prev.and_then(|p| self.arcs[p].as_mut()) {
+            Some(prev_arc) => prev_arc.tail_link = Some(arc_idx),
+            None => self.vertices[from].first_out = Some(arc_idx),
+        }
+        match in_prev.and_then(|p| self.arcs[p].as_mut()) {
+            Some(prev_arc) => prev_arc.head_link = Some(arc_idx),
+            None => self.vertices[to].first_in = Some(arc_idx),
+        }
 
         self.edge_count += 1;
     }
@@ -210,11 +230,270 @@ where
             self.edge_count -= 1;
         }
     }
+
+    /// 删除一个顶点，并从各顶点的出弧、入弧链表中摘除依附于它的所有弧
+    ///
+    /// 顶点下标保持连续：下标大于 `vertex` 的顶点依次前移一位，弧上记录的弧尾、弧头随之更新。
+    /// 弧在存储池中的下标不变，被删除的弧只留下空槽位。
+    ///
+    /// # 参数
+    /// * `vertex` - 顶点索引
+    ///
+    /// # 返回值
+    /// 返回被删除顶点的数据。十字链表的顶点总是带有数据，因此总是返回Some
+    ///
+    /// # Panics
+    /// 当顶点索引超出范围时会panic
+    pub fn remove_vertex(&mut self, vertex: usize) -> Option<T> {
+        if vertex >= self.vertices.len() {
+            panic!("Vertex index out of bounds");
+        }
+
+        let out: Vec<(usize, usize)> = self
+            .out_arcs(vertex)
+            .map(|(idx, arc)| (idx, arc.head_vex))
+            .collect();
+        let incoming: Vec<(usize, usize)> = self
+            .in_arcs(vertex)
+            .map(|(idx, arc)| (idx, arc.tail_vex))
+            .collect();
+
+        // 自环只在该顶点自己的链表中，随顶点一起删除即可
+        for &(idx, head) in &out {
+            if head != vertex {
+                self.unlink_in_arc(head, idx);
+            }
+        }
+        for &(idx, tail) in &incoming {
+            if tail != vertex {
+                self.unlink_out_arc(tail, idx);
+            }
+        }
+        for &(idx, _) in out.iter().chain(&incoming) {
+            if self.arcs[idx].take().is_some() {
+                self.edge_count -= 1;
+            }
+        }
+
+        for arc in self.arcs.iter_mut().flatten() {
+            if arc.tail_vex > vertex {
+                arc.tail_vex -= 1;
+            }
+            if arc.head_vex > vertex {
+                arc.head_vex -= 1;
+            }
+        }
+        Some(self.vertices.remove(vertex).data)
+    }
+
+    // 辅助函数：把弧从顶点的出弧链表中摘除
+    fn unlink_out_arc(&mut self, vertex: usize, target: usize) {
+        let next = self.arcs[target].as_ref().and_then(|arc| arc.tail_link);
+        let prev = self
+            .out_arcs(vertex)
+            .find(|(_, arc)| arc.tail_link == Some(target))
+            .map(|(idx, _)| idx);
+        match prev.and_then(|p| self.arcs[p].as_mut()) {
+            Some(prev_arc) => prev_arc.tail_link = next,
+            None => self.vertices[vertex].first_out = next,
+        }
+    }
+
+    // 辅助函数：把弧从顶点的入弧链表中摘除
+    fn unlink_in_arc(&mut self, vertex: usize, target: usize) {
+        let next = self.arcs[target].as_ref().and_then(|arc| arc.head_link);
+        let prev = self
+            .in_arcs(vertex)
+            .find(|(_, arc)| arc.head_link == Some(target))
+            .map(|(idx, _)| idx);
+        match prev.and_then(|p| self.arcs[p].as_mut()) {
+            Some(prev_arc) => prev_arc.head_link = next,
+            None => self.vertices[vertex].first_in = next,
+        }
+    }
+}
+
+impl<T, W> OrthogonalList<T, W> {
+    /// 遍历顶点的所有出弧（沿 `first_out` / `tail_link` 链）
+    ///
+    /// 迭代器产出 (弧下标, 弧)
+    pub fn out_arcs(&self, vertex: usize) -> OutArcs<'_, W> {
+        OutArcs {
+            arcs: &self.arcs,
+            current: self.vertices.get(vertex).and_then(|v| v.first_out),
+        }
+    }
+
+    /// 遍历顶点的所有入弧（沿 `first_in` / `head_link` 链）
+    ///
+    /// 迭代器产出 (弧下标, 弧)
+    pub fn in_arcs(&self, vertex: usize) -> InArcs<'_, W> {
+        InArcs {
+            arcs: &self.arcs,
+            current: self.vertices.get(vertex).and_then(|v| v.first_in),
+        }
+    }
+
+    /// 获取顶点的出度
+    pub fn out_degree(&self, vertex: usize) -> usize {
+        self.out_arcs(vertex).count()
+    }
+
+    /// 获取顶点的入度
+    pub fn in_degree(&self, vertex: usize) -> usize {
+        self.in_arcs(vertex).count()
+    }
+}
+
+/// 在弧链表中查找第一条与新弧平行的弧，返回 (它的前驱弧, 它的下标)
+fn parallel_position<'a, W: 'a>(
+    chain: impl Iterator<Item = (usize, &'a OLArc<W>)>,
+    is_parallel: impl Fn(&OLArc<W>) -> bool,
+) -> Option<(Option<usize>, usize)> {
+    let mut prev = None;
+    for (idx, arc) in chain {
+        if is_parallel(arc) {
+            return Some((prev, idx));
+        }
+        prev = Some(idx);
+    }
+    None
+}
+
+/// 顶点出弧的迭代器，由 [`OrthogonalList::out_arcs`] 创建
+pub struct OutArcs<'a, W> {
+    arcs: &'a [Option<OLArc<W>>],
+    current: Option<usize>,
+}
+
+impl<'a, W> Iterator for OutArcs<'a, W> {
+    type Item = (usize, &'a OLArc<W>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.current?;
+        let arc = self.arcs[idx].as_ref()?;
+        self.current = arc.tail_link;
+        Some((idx, arc))
+    }
+}
+
+/// 顶点入弧的迭代器，由 [`OrthogonalList::in_arcs`] 创建
+pub struct InArcs<'a, W> {
+    arcs: &'a [Option<OLArc<W>>],
+    current: Option<usize>,
+}
+
+impl<'a, W> Iterator for InArcs<'a, W> {
+    type Item = (usize, &'a OLArc<W>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.current?;
+        let arc = self.arcs[idx].as_ref()?;
+        self.current = arc.head_link;
+        Some((idx, arc))
+    }
+}
+
+// 为 OrthogonalList 实现 GraphNeighbor trait，沿 first_out / tail_link 遍历出弧
+impl<T, W> GraphNeighbor for OrthogonalList<T, W> {
+    fn first_neighbor(&self, vertex: usize) -> Option<usize> {
+        self.out_arcs(vertex).next().map(|(_, arc)| arc.head_vex)
+    }
+
+    fn next_neighbor(&self, vertex: usize, current_neighbor: usize) -> Option<usize> {
+        // 平行弧指向同一个顶点，只产出一次
+        next_distinct_neighbor(
+            self.out_arcs(vertex).map(|(_, arc)| arc.head_vex),
+            current_neighbor,
+        )
+    }
+}
+
+// 为 OrthogonalList 实现 GraphInNeighbor trait，沿 first_in / head_link 遍历入弧
+impl<T, W> GraphInNeighbor for OrthogonalList<T, W> {
+    fn first_in_neighbor(&self, vertex: usize) -> Option<usize> {
+        self.in_arcs(vertex).next().map(|(_, arc)| arc.tail_vex)
+    }
+
+    fn next_in_neighbor(&self, vertex: usize, current_neighbor: usize) -> Option<usize> {
+        next_distinct_neighbor(
+            self.in_arcs(vertex).map(|(_, arc)| arc.tail_vex),
+            current_neighbor,
+        )
+    }
+}
+
+// 为 OrthogonalList 实现 EdgeWeight trait，存在平行弧时 edge_weight 返回最近添加的一条，
+// edge_weights 产出所有平行弧
+impl<T, W> EdgeWeight for OrthogonalList<T, W>
+where
+    W: Clone,
+{
+    type Weight = W;
+
+    fn edge_weight(&self, from: usize, to: usize) -> Option<&W> {
+        self.get_edge(from, to)
+    }
+
+    fn edge_weights(&self, from: usize, to: usize) -> impl Iterator<Item = &W> {
+        // 平行弧在出弧链表中相邻
+        self.out_arcs(from)
+            .skip_while(move |(_, arc)| arc.head_vex != to)
+            .take_while(move |(_, arc)| arc.head_vex == to)
+            .map(|(_, arc)| &arc.weight)
+    }
+}
+
+// 为 OrthogonalList 实现 Graph trait
+impl<T, W> Graph for OrthogonalList<T, W>
+where
+    W: Clone,
+{
+    type VertexData = T;
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    fn add_vertex(&mut self, data: T) -> usize {
+        OrthogonalList::add_vertex(self, data)
+    }
+
+    fn remove_vertex(&mut self, vertex: usize) -> Option<T> {
+        OrthogonalList::remove_vertex(self, vertex)
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        OrthogonalList::add_edge(self, from, to, weight);
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) {
+        OrthogonalList::remove_edge(self, from, to);
+    }
+
+    fn vertex_data(&self, vertex: usize) -> Option<&T> {
+        self.get_vertex_data(vertex)
+    }
+
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, &W)> {
+        if vertex >= self.vertices.len() {
+            panic!("Vertex index out of bounds");
+        }
+        self.out_arcs(vertex)
+            .map(|(_, arc)| (arc.head_vex, &arc.weight))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::traversal::{
+        CollectVisitor, Reversed, breadth_first_search, depth_first_search,
+    };
 
     #[test]
     fn test_orthogonal_list() {
@@ -234,17 +513,136 @@ mod tests {
         // V3 -> V0, weight 40
         ol.add_edge(v3, v0, 40);
 
-        assert_eq!(ol.edge_count, 4);
+        assert_eq!(ol.edge_count(), 4);
         assert_eq!(ol.get_edge(v0, v1), Some(&10));
         assert_eq!(ol.get_edge(v3, v0), Some(&40));
         assert_eq!(ol.get_edge(v1, v2), None);
 
         // Remove edge V0 -> V2
         ol.remove_edge(v0, v2);
-        assert_eq!(ol.edge_count, 3);
+        assert_eq!(ol.edge_count(), 3);
         assert_eq!(ol.get_edge(v0, v2), None);
 
         // Check linked list integrity
         assert_eq!(ol.get_edge(v0, v1), Some(&10));
     }
+
+    #[test]
+    fn test_arc_iterators() {
+        let mut ol = OrthogonalList::<&str, i32>::new();
+        for name in ["V0", "V1", "V2"] {
+            ol.add_vertex(name);
+        }
+        ol.add_edge(0, 1, 1);
+        ol.add_edge(0, 2, 2);
+        ol.add_edge(2, 1, 3);
+
+        // 头插法，后添加的弧排在前面
+        let out: Vec<usize> = ol.out_arcs(0).map(|(_, arc)| arc.head_vex).collect();
+        assert_eq!(out, vec![2, 1]);
+        let into: Vec<usize> = ol.in_arcs(1).map(|(_, arc)| arc.tail_vex).collect();
+        assert_eq!(into, vec![2, 0]);
+
+        assert_eq!(ol.out_degree(0), 2);
+        assert_eq!(ol.in_degree(1), 2);
+        assert_eq!(ol.in_degree(0), 0);
+
+        ol.remove_edge(0, 1);
+        assert_eq!(ol.out_degree(0), 1);
+        assert_eq!(ol.in_degree(1), 1);
+    }
+
+    #[test]
+    fn test_remove_vertex_orthogonal_list() {
+        let mut ol = OrthogonalList::<&str, i32>::new();
+        for name in ["A", "B", "C", "D"] {
+            ol.add_vertex(name);
+        }
+        ol.add_edge(0, 1, 1);
+        ol.add_edge(1, 2, 2);
+        ol.add_edge(2, 1, 3);
+        ol.add_edge(1, 1, 4);
+        ol.add_edge(2, 3, 5);
+        ol.add_edge(3, 0, 6);
+        ol.add_edge(0, 2, 7);
+
+        // 删除B，C、D前移为1、2
+        assert_eq!(ol.remove_vertex(1), Some("B"));
+        assert_eq!(ol.vertices.len(), 3);
+        assert_eq!(ol.edge_count(), 3);
+        assert_eq!(ol.get_vertex_data(1), Some(&"C"));
+        assert_eq!(ol.get_edge(1, 2), Some(&5));
+        assert_eq!(ol.get_edge(2, 0), Some(&6));
+        assert_eq!(ol.get_edge(0, 1), Some(&7));
+
+        // 出弧和入弧链表都已摘除被删除的弧
+        assert_eq!(ol.out_degree(0), 1);
+        assert_eq!(ol.in_degree(0), 1);
+        assert_eq!(ol.out_degree(1), 1);
+        assert_eq!(ol.in_degree(1), 1);
+        assert_eq!(ol.in_degree(2), 1);
+
+        ol.remove_edge(0, 1);
+        assert_eq!(ol.in_degree(1), 0);
+        assert_eq!(ol.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_parallel_arcs_orthogonal_list() {
+        let mut ol = OrthogonalList::<(), i32>::new();
+        for _ in 0..4 {
+            ol.add_vertex(());
+        }
+        ol.add_edge(0, 1, 1);
+        ol.add_edge(3, 1, 2);
+        ol.add_edge(0, 2, 2);
+        ol.add_edge(0, 1, 3);
+
+        // 平行弧插在已有的同向弧前面，在出弧、入弧链表中都相邻
+        let heads: Vec<usize> = ol.out_arcs(0).map(|(_, arc)| arc.head_vex).collect();
+        assert_eq!(heads, vec![2, 1, 1]);
+        let tails: Vec<usize> = ol.in_arcs(1).map(|(_, arc)| arc.tail_vex).collect();
+        assert_eq!(tails, vec![3, 0, 0]);
+
+        assert_eq!(ol.first_neighbor(0), Some(2));
+        assert_eq!(ol.next_neighbor(0, 2), Some(1));
+        assert_eq!(ol.next_neighbor(0, 1), None);
+        assert_eq!(ol.first_in_neighbor(1), Some(3));
+        assert_eq!(ol.next_in_neighbor(1, 3), Some(0));
+        assert_eq!(ol.next_in_neighbor(1, 0), None);
+
+        // 最近添加的平行弧排在前面
+        assert_eq!(ol.get_edge(0, 1), Some(&3));
+        ol.remove_edge(0, 1);
+        assert_eq!(ol.get_edge(0, 1), Some(&1));
+        assert_eq!(ol.in_degree(1), 2);
+    }
+
+    #[test]
+    fn test_traversal_orthogonal_list() {
+        // 0 → 1 → 3, 0 → 2 → 3，另有一条平行弧 0 → 1
+        let mut ol = OrthogonalList::<(), i32>::new();
+        for _ in 0..5 {
+            ol.add_vertex(());
+        }
+        ol.add_edge(2, 3, 1);
+        ol.add_edge(1, 3, 1);
+        ol.add_edge(0, 2, 1);
+        ol.add_edge(0, 1, 1);
+        ol.add_edge(0, 1, 2);
+
+        // 头插法：0 的出弧顺序为 1, 1, 2，平行弧只产出一次
+        assert_eq!(ol.first_neighbor(0), Some(1));
+        assert_eq!(ol.next_neighbor(0, 1), Some(2));
+        assert_eq!(ol.next_neighbor(0, 2), None);
+
+        let mut visitor = CollectVisitor::default();
+        breadth_first_search(&ol, 0, &mut visitor, 5);
+        assert_eq!(visitor.order, vec![0, 1, 2, 3]);
+
+        // 沿入弧反向遍历：能到达 3 的顶点
+        let mut visitor = CollectVisitor::default();
+        depth_first_search(&Reversed(&ol), 3, &mut visitor, 5);
+        assert_eq!(visitor.order, vec![3, 1, 0, 2]);
+    }
 }
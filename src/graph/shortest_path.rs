@@ -0,0 +1,540 @@
+//! 带权图的最短路径算法
+
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt;
+
+use super::adjacency_matrix::AdjacencyMatrix;
+use super::symmetric_matrix::SymmetricMatrix;
+use super::traversal::{EdgeWeight, GraphNeighbor};
+use super::weight::{MinScored, Weight};
+
+/// 单源最短路径的结果
+///
+/// # 类型参数
+/// * `D` - 路径长度的类型
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortestPaths<D> {
+    /// 起始顶点
+    pub start: usize,
+    /// 每个顶点到起点的最短距离，不可达的顶点为None
+    pub distances: Vec<Option<D>>,
+    /// 每个顶点在最短路径树中的前驱顶点，起点和不可达的顶点为None
+    pub predecessors: Vec<Option<usize>>,
+}
+
+impl<D> ShortestPaths<D>
+where
+    D: Copy,
+{
+    /// 获取起点到目标顶点的最短距离，不可达时返回None
+    pub fn distance_to(&self, target: usize) -> Option<D> {
+        self.distances.get(target).copied().flatten()
+    }
+
+    /// 重建从起点到目标顶点的最短路径
+    ///
+    /// # 参数
+    /// * `target` - 目标顶点索引
+    ///
+    /// # 返回值
+    /// 返回从起点到目标顶点依次经过的顶点（包含两端），目标不可达时返回None
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distance_to(target)?;
+
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(prev) = self.predecessors[current] {
+            path.push(prev);
+            current = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Dijkstra 单源最短路径算法（二叉堆实现）
+///
+/// 堆操作共需 O((V + E) log V)，此外每条边要调用一次 `next_neighbor` 和 `edge_weight`，
+/// 总耗时取决于图结构提供这两个操作的代价：邻接矩阵逐行扫描共 O(V²)，
+/// 邻接表二者都是线性查找，最坏为各顶点出度平方之和。
+/// 要求所有边的权重非负，存在负权边时请使用 Bellman-Ford 算法。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor 和 EdgeWeight trait 的图结构
+/// * `start` - 起始顶点索引
+/// * `vertex_count` - 图中顶点的总数
+/// * `weight_of` - 从边上存储的数据中取出权重的闭包，例如 `|w| *w` 或 `|road| road.distance`
+///
+/// # 泛型参数
+/// * `G` - 图类型
+/// * `D` - 路径长度类型，必须实现 Weight trait
+/// * `F` - 权重提取闭包
+pub fn dijkstra<G, D, F>(
+    graph: &G,
+    start: usize,
+    vertex_count: usize,
+    weight_of: F,
+) -> ShortestPaths<D>
+where
+    G: GraphNeighbor + EdgeWeight,
+    D: Weight,
+    F: Fn(&G::Weight) -> D,
+{
+    let mut distances: Vec<Option<D>> = vec![None; vertex_count];
+    let mut predecessors = vec![None; vertex_count];
+    // 已经确定最短距离的顶点
+    let mut settled = vec![false; vertex_count];
+    let mut heap = BinaryHeap::new();
+
+    distances[start] = Some(D::zero());
+    heap.push(MinScored {
+        weight: D::zero(),
+        vertex: start,
+    });
+
+    while let Some(MinScored { weight, vertex }) = heap.pop() {
+        // 堆中可能残留同一顶点的旧记录，直接跳过
+        if settled[vertex] {
+            continue;
+        }
+        settled[vertex] = true;
+
+        // 松弛所有出边
+        let mut neighbor = graph.first_neighbor(vertex);
+        while let Some(next) = neighbor {
+            for edge in graph.edge_weights(vertex, next) {
+                let candidate = weight + weight_of(edge);
+                let shorter = match distances[next] {
+                    Some(current) => candidate < current,
+                    None => true,
+                };
+                if !settled[next] && shorter {
+                    distances[next] = Some(candidate);
+                    predecessors[next] = Some(vertex);
+                    heap.push(MinScored {
+                        weight: candidate,
+                        vertex: next,
+                    });
+                }
+            }
+            neighbor = graph.next_neighbor(vertex, next);
+        }
+    }
+
+    ShortestPaths {
+        start,
+        distances,
+        predecessors,
+    }
+}
+
+/// 图中存在从起点可达的负权环，此时最短路径没有定义
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle {
+    /// 环上的顶点，按边的方向排列，最后一个顶点有边指回第一个顶点
+    pub cycle: Vec<usize>,
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative cycle detected: {:?}", self.cycle)
+    }
+}
+
+impl Error for NegativeCycle {}
+
+/// Bellman-Ford 单源最短路径算法，支持负权边
+///
+/// 时间复杂度为 O(VE)，某一轮没有发生松弛时提前结束。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor 和 EdgeWeight trait 的图结构
+/// * `start` - 起始顶点索引
+/// * `vertex_count` - 图中顶点的总数
+/// * `weight_of` - 从边上存储的数据中取出权重的闭包
+///
+/// # 返回值
+/// 返回最短路径；如果存在从起点可达的负权环，返回包含该环顶点的 [`NegativeCycle`] 错误
+pub fn bellman_ford<G, D, F>(
+    graph: &G,
+    start: usize,
+    vertex_count: usize,
+    weight_of: F,
+) -> Result<ShortestPaths<D>, NegativeCycle>
+where
+    G: GraphNeighbor + EdgeWeight,
+    D: Weight,
+    F: Fn(&G::Weight) -> D,
+{
+    // 先收集所有边，避免每一轮都重新遍历邻接结构
+    let mut edges = Vec::new();
+    for vertex in 0..vertex_count {
+        let mut neighbor = graph.first_neighbor(vertex);
+        while let Some(next) = neighbor {
+            for edge in graph.edge_weights(vertex, next) {
+                edges.push((vertex, next, weight_of(edge)));
+            }
+            neighbor = graph.next_neighbor(vertex, next);
+        }
+    }
+
+    let mut distances: Vec<Option<D>> = vec![None; vertex_count];
+    let mut predecessors = vec![None; vertex_count];
+    distances[start] = Some(D::zero());
+
+    // 尝试用边 (from, to, weight) 松弛，成功时返回true
+    let relax = |distances: &mut [Option<D>],
+                 predecessors: &mut [Option<usize>],
+                 (from, to, weight): (usize, usize, D)| {
+        let Some(base) = distances[from] else {
+            return false;
+        };
+        let candidate = base + weight;
+        let shorter = match distances[to] {
+            Some(current) => candidate < current,
+            None => true,
+        };
+        if shorter {
+            distances[to] = Some(candidate);
+            predecessors[to] = Some(from);
+        }
+        shorter
+    };
+
+    // 最多进行 V-1 轮松弛
+    for _ in 1..vertex_count {
+        let mut changed = false;
+        for &edge in &edges {
+            changed |= relax(&mut distances, &mut predecessors, edge);
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    // 第 V 轮仍能松弛，说明存在可达的负权环
+    for &edge in &edges {
+        if relax(&mut distances, &mut predecessors, edge) {
+            return Err(NegativeCycle {
+                cycle: extract_cycle(&predecessors, edge.1, vertex_count),
+            });
+        }
+    }
+
+    Ok(ShortestPaths {
+        start,
+        distances,
+        predecessors,
+    })
+}
+
+/// 从刚被松弛的顶点出发，沿前驱找出负权环
+pub(super) fn extract_cycle(
+    predecessors: &[Option<usize>],
+    from: usize,
+    vertex_count: usize,
+) -> Vec<usize> {
+    // 沿前驱回退 V 步，此时一定已经落在环上
+    let mut on_cycle = from;
+    for _ in 0..vertex_count {
+        on_cycle = predecessors[on_cycle].expect("relaxed vertex must have a predecessor");
+    }
+
+    // 沿前驱绕环一周，得到逆序的环
+    let mut cycle = vec![on_cycle];
+    let mut current = predecessors[on_cycle].expect("cycle vertex must have a predecessor");
+    while current != on_cycle {
+        cycle.push(current);
+        current = predecessors[current].expect("cycle vertex must have a predecessor");
+    }
+    cycle.reverse();
+    cycle
+}
+
+/// 全源最短路径的结果
+///
+/// # 类型参数
+/// * `D` - 路径长度的类型
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllPairsShortestPaths<D> {
+    /// `distances[i][j]` 为顶点i到顶点j的最短距离，不可达时为None
+    pub distances: Vec<Vec<Option<D>>>,
+    /// `next_hops[i][j]` 为从顶点i到顶点j的最短路径上i之后的下一个顶点
+    pub next_hops: Vec<Vec<Option<usize>>>,
+}
+
+impl<D> AllPairsShortestPaths<D>
+where
+    D: Weight,
+{
+    /// 获取顶点 `from` 到顶点 `to` 的最短距离，不可达时返回None
+    pub fn distance(&self, from: usize, to: usize) -> Option<D> {
+        self.distances[from][to]
+    }
+
+    /// 判断图中是否存在负权环
+    ///
+    /// 存在负权环时，经过环上顶点的距离和路径都没有意义。
+    pub fn has_negative_cycle(&self) -> bool {
+        (0..self.distances.len()).any(|i| matches!(self.distances[i][i], Some(d) if d < D::zero()))
+    }
+
+    /// 利用下一跳矩阵重建从 `from` 到 `to` 的最短路径
+    ///
+    /// # 返回值
+    /// 返回依次经过的顶点（包含两端），不可达时返回None
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.distances[from][to]?;
+
+        let mut path = vec![from];
+        let mut current = from;
+        while current != to {
+            current = self.next_hops[current][to]?;
+            path.push(current);
+        }
+        Some(path)
+    }
+
+    /// 将距离矩阵压缩存储到 [`SymmetricMatrix`] 中，只保存下三角部分
+    ///
+    /// 适用于无向图（每条边双向存储）的结果，可以节省约一半的内存。
+    /// 下一跳矩阵本身不对称，不包含在返回值中。
+    ///
+    /// # 返回值
+    /// 距离矩阵不对称时返回None
+    pub fn symmetric_distances(&self) -> Option<SymmetricMatrix<Option<D>>> {
+        let size = self.distances.len();
+        let mut matrix = SymmetricMatrix::new(size);
+        for i in 0..size {
+            for j in 0..=i {
+                if self.distances[i][j] != self.distances[j][i] {
+                    return None;
+                }
+                matrix.set(i, j, self.distances[i][j]);
+            }
+        }
+        Some(matrix)
+    }
+}
+
+/// Floyd-Warshall 全源最短路径算法
+///
+/// 直接在邻接矩阵上进行动态规划，时间复杂度为 O(V³)。允许负权边；
+/// 如果存在负权环，可以通过 [`AllPairsShortestPaths::has_negative_cycle`] 检查。
+///
+/// # 参数
+/// * `graph` - 邻接矩阵
+/// * `weight_of` - 从边上存储的数据中取出权重的闭包
+///
+/// # 返回值
+/// 返回距离矩阵和用于重建路径的下一跳矩阵
+pub fn floyd_warshall<T, W, D, F>(
+    graph: &AdjacencyMatrix<T, W>,
+    weight_of: F,
+) -> AllPairsShortestPaths<D>
+where
+    W: Clone,
+    D: Weight,
+    F: Fn(&W) -> D,
+{
+    let n = graph.vertices();
+    let mut distances: Vec<Vec<Option<D>>> = vec![vec![None; n]; n];
+    let mut next_hops = vec![vec![None; n]; n];
+
+    // 初始化：对角线为0，其余为边的权重
+    for i in 0..n {
+        distances[i][i] = Some(D::zero());
+        next_hops[i][i] = Some(i);
+        for j in 0..n {
+            if let Some(edge) = graph.get_edge(i, j) {
+                let weight = weight_of(edge);
+                // 自环只有在为负权时才会缩短距离
+                if i != j || weight < D::zero() {
+                    distances[i][j] = Some(weight);
+                    next_hops[i][j] = Some(j);
+                }
+            }
+        }
+    }
+
+    // 依次允许经过顶点 k 作为中转
+    for k in 0..n {
+        for i in 0..n {
+            let Some(via) = distances[i][k] else {
+                continue;
+            };
+            for j in 0..n {
+                let Some(rest) = distances[k][j] else {
+                    continue;
+                };
+                let candidate = via + rest;
+                let shorter = match distances[i][j] {
+                    Some(current) => candidate < current,
+                    None => true,
+                };
+                if shorter {
+                    distances[i][j] = Some(candidate);
+                    next_hops[i][j] = next_hops[i][k];
+                }
+            }
+        }
+    }
+
+    AllPairsShortestPaths {
+        distances,
+        next_hops,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{AdjacencyList, AdjacencyMatrix, OrthogonalList};
+
+    #[test]
+    fn test_dijkstra() {
+        // 有向带权图，边: 0→1(4), 0→2(1), 2→1(2), 1→3(1), 2→3(5)，顶点4不可达
+        let mut graph = AdjacencyList::<&str, u32>::new(5);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(0, 2, 1);
+        graph.add_edge(2, 1, 2);
+        graph.add_edge(1, 3, 1);
+        graph.add_edge(2, 3, 5);
+
+        let result = dijkstra(&graph, 0, 5, |w| *w);
+        assert_eq!(
+            result.distances,
+            vec![Some(0), Some(3), Some(1), Some(4), None]
+        );
+        assert_eq!(result.path_to(3), Some(vec![0, 2, 1, 3]));
+        assert_eq!(result.path_to(0), Some(vec![0]));
+        assert_eq!(result.path_to(4), None);
+    }
+
+    #[test]
+    fn test_parallel_arcs() {
+        // 十字链表中 0 → 1 有两条权重不同的平行弧，后添加的权重更大
+        let mut graph = OrthogonalList::<(), i32>::new();
+        for _ in 0..3 {
+            graph.add_vertex(());
+        }
+        graph.add_edge(0, 1, 5);
+        graph.add_edge(0, 1, 9);
+        graph.add_edge(1, 2, 1);
+
+        let result = dijkstra(&graph, 0, 3, |w| *w);
+        assert_eq!(result.distances, vec![Some(0), Some(5), Some(6)]);
+
+        let result = bellman_ford(&graph, 0, 3, |w| *w).unwrap();
+        assert_eq!(result.distances, vec![Some(0), Some(5), Some(6)]);
+    }
+
+    #[test]
+    fn test_dijkstra_float_weights() {
+        #[derive(Debug, Clone)]
+        struct Road {
+            distance: f64,
+        }
+
+        let mut graph = AdjacencyMatrix::<String, Road>::new(3);
+        graph.add_edge(0, 1, Some(Road { distance: 1.5 }));
+        graph.add_edge(1, 2, Some(Road { distance: 2.25 }));
+        graph.add_edge(0, 2, Some(Road { distance: 4.0 }));
+
+        let result = dijkstra(&graph, 0, 3, |road| road.distance);
+        assert_eq!(result.distance_to(2), Some(3.75));
+        assert_eq!(result.path_to(2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn test_bellman_ford_negative_edges() {
+        // 0→1(4), 0→2(5), 2→1(-3)（返利）, 1→3(2)
+        let mut graph = AdjacencyList::<&str, i32>::new(4);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(0, 2, 5);
+        graph.add_edge(2, 1, -3);
+        graph.add_edge(1, 3, 2);
+
+        let result = bellman_ford(&graph, 0, 4, |w| *w).unwrap();
+        assert_eq!(result.distances, vec![Some(0), Some(2), Some(5), Some(4)]);
+        assert_eq!(result.path_to(3), Some(vec![0, 2, 1, 3]));
+    }
+
+    #[test]
+    fn test_bellman_ford_negative_cycle() {
+        // 0→1(1), 1→2(-2), 2→3(1), 3→1(-1) 构成负权环 1→2→3→1，另有 3→4(1)
+        let mut graph = AdjacencyMatrix::<&str, i32>::new(5);
+        graph.add_edge(0, 1, Some(1));
+        graph.add_edge(1, 2, Some(-2));
+        graph.add_edge(2, 3, Some(1));
+        graph.add_edge(3, 1, Some(-1));
+        graph.add_edge(3, 4, Some(1));
+
+        let err = bellman_ford(&graph, 0, 5, |w| *w).unwrap_err();
+        let mut cycle = err.cycle.clone();
+        assert_eq!(cycle.len(), 3);
+
+        // 环上相邻顶点之间都有边，且总权重为负
+        let mut total = 0;
+        for i in 0..cycle.len() {
+            let from = cycle[i];
+            let to = cycle[(i + 1) % cycle.len()];
+            total += *graph.get_edge(from, to).unwrap();
+        }
+        assert!(total < 0);
+
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2, 3]);
+
+        // 负权环不可达时正常返回
+        let result = bellman_ford(&graph, 4, 5, |w| *w).unwrap();
+        assert_eq!(result.distances, vec![None, None, None, None, Some(0)]);
+    }
+
+    #[test]
+    fn test_floyd_warshall() {
+        // 有向图: 0→1(3), 0→2(8), 1→2(2), 2→0(-1), 2→3(1)
+        let mut graph = AdjacencyMatrix::<&str, i32>::new(4);
+        graph.add_edge(0, 1, Some(3));
+        graph.add_edge(0, 2, Some(8));
+        graph.add_edge(1, 2, Some(2));
+        graph.add_edge(2, 0, Some(-1));
+        graph.add_edge(2, 3, Some(1));
+
+        let result = floyd_warshall(&graph, |w| *w);
+        assert!(!result.has_negative_cycle());
+        assert_eq!(result.distance(0, 3), Some(6));
+        assert_eq!(result.path(0, 3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(result.distance(1, 0), Some(1));
+        assert_eq!(result.path(1, 0), Some(vec![1, 2, 0]));
+        assert_eq!(result.distance(3, 0), None);
+        assert_eq!(result.path(3, 0), None);
+        assert_eq!(result.path(2, 2), Some(vec![2]));
+
+        // 与Dijkstra/Bellman-Ford的单源结果一致
+        let single = bellman_ford(&graph, 0, 4, |w| *w).unwrap();
+        assert_eq!(single.distances, result.distances[0]);
+
+        // 有向图的距离矩阵不对称
+        assert!(result.symmetric_distances().is_none());
+    }
+
+    #[test]
+    fn test_floyd_warshall_undirected() {
+        // 无向图（双向存储）: 0-1(1), 1-2(2), 0-2(5), 顶点3孤立
+        let mut graph = AdjacencyMatrix::<&str, u32>::new(4);
+        for (u, v, w) in [(0, 1, 1), (1, 2, 2), (0, 2, 5)] {
+            graph.add_edge(u, v, Some(w));
+            graph.add_edge(v, u, Some(w));
+        }
+
+        let result = floyd_warshall(&graph, |w| *w);
+        let symmetric = result.symmetric_distances().unwrap();
+        assert_eq!(symmetric.size(), 4);
+        assert_eq!(symmetric.get(0, 2), Some(3));
+        assert_eq!(symmetric.get(2, 0), Some(3));
+        assert_eq!(symmetric.get(3, 1), None);
+        assert_eq!(symmetric.to_matrix(), result.distances);
+    }
+}
@@ -0,0 +1,444 @@
+//! 无向带权图的最小生成树算法
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use super::adjacency_matrix::AdjacencyMatrix;
+use super::adjacency_multilist::AdjacencyMultilist;
+use super::traversal::{EdgeWeight, GraphNeighbor};
+use super::weight::{MinScored, Weight};
+use crate::union_find::UnionFind;
+
+/// 生成树中的一条边，始终满足 `u <= v`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpanningEdge<D> {
+    /// 编号较小的端点
+    pub u: usize,
+    /// 编号较大的端点
+    pub v: usize,
+    /// 边的权重
+    pub weight: D,
+}
+
+impl<D> SpanningEdge<D> {
+    /// 创建一条边，自动调整端点顺序
+    pub fn new(a: usize, b: usize, weight: D) -> Self {
+        let (u, v) = if a <= b { (a, b) } else { (b, a) };
+        SpanningEdge { u, v, weight }
+    }
+}
+
+/// 最小生成树（森林）的结果
+///
+/// 图不连通时得到的是最小生成森林，`components` 为连通分量的个数。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinimumSpanningTree<D> {
+    /// 被选中的边，按加入生成树的顺序排列
+    pub edges: Vec<SpanningEdge<D>>,
+    /// 所有被选中边的权重之和
+    pub total_weight: D,
+    /// 生成森林中树的个数，即图的连通分量个数
+    pub components: usize,
+}
+
+impl<D> MinimumSpanningTree<D> {
+    /// 判断结果是否是一棵覆盖所有顶点的生成树（即原图连通）
+    pub fn is_spanning_tree(&self) -> bool {
+        self.components <= 1
+    }
+}
+
+/// Kruskal 算法的核心过程：按权重从小到大选边，用并查集排除会形成环的边
+///
+/// 时间复杂度为 O(E log E)。
+///
+/// # 参数
+/// * `vertex_count` - 图中顶点的总数
+/// * `edges` - 图中所有的无向边，允许重复出现
+pub fn kruskal_from_edges<D>(
+    vertex_count: usize,
+    mut edges: Vec<SpanningEdge<D>>,
+) -> MinimumSpanningTree<D>
+where
+    D: Weight,
+{
+    edges.sort_by(|a, b| a.weight.partial_cmp(&b.weight).unwrap_or(Ordering::Equal));
+
+    let mut sets = UnionFind::new(vertex_count);
+    let mut chosen = Vec::new();
+    let mut total_weight = D::zero();
+
+    for edge in edges {
+        let root_u = sets
+            .find(edge.u as isize)
+            .expect("vertex index out of bounds");
+        let root_v = sets
+            .find(edge.v as isize)
+            .expect("vertex index out of bounds");
+        // 两个端点已经连通，加入这条边会形成环
+        if root_u == root_v {
+            continue;
+        }
+        sets.union(root_u, root_v)
+            .expect("vertex index out of bounds");
+        total_weight = total_weight + edge.weight;
+        chosen.push(edge);
+        if chosen.len() + 1 == vertex_count {
+            break;
+        }
+    }
+
+    MinimumSpanningTree {
+        components: vertex_count - chosen.len(),
+        edges: chosen,
+        total_weight,
+    }
+}
+
+/// 在无向图上运行 Kruskal 算法
+///
+/// 图中的每条边 `u -> v` 都被视为无向边 `(u, v)`，
+/// 因此双向存储的无向图和只存储单向边的图都可以使用；自环会被忽略。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor 和 EdgeWeight trait 的图结构
+/// * `vertex_count` - 图中顶点的总数
+/// * `weight_of` - 从边上存储的数据中取出权重的闭包
+pub fn kruskal<G, D, F>(graph: &G, vertex_count: usize, weight_of: F) -> MinimumSpanningTree<D>
+where
+    G: GraphNeighbor + EdgeWeight,
+    D: Weight,
+    F: Fn(&G::Weight) -> D,
+{
+    let mut edges = Vec::new();
+    for vertex in 0..vertex_count {
+        let mut neighbor = graph.first_neighbor(vertex);
+        while let Some(next) = neighbor {
+            if vertex != next {
+                for edge in graph.edge_weights(vertex, next) {
+                    edges.push(SpanningEdge::new(vertex, next, weight_of(edge)));
+                }
+            }
+            neighbor = graph.next_neighbor(vertex, next);
+        }
+    }
+    kruskal_from_edges(vertex_count, edges)
+}
+
+/// 在邻接多重表存储的无向图上运行 Kruskal 算法
+///
+/// 邻接多重表中每条无向边只存储一次，直接遍历边表即可。
+///
+/// # 参数
+/// * `graph` - 邻接多重表
+/// * `weight_of` - 从边上存储的数据中取出权重的闭包
+pub fn kruskal_multilist<T, W, D, F>(
+    graph: &AdjacencyMultilist<T, W>,
+    weight_of: F,
+) -> MinimumSpanningTree<D>
+where
+    W: Clone,
+    D: Weight,
+    F: Fn(&W) -> D,
+{
+    let edges = graph
+        .edges
+        .iter()
+        .flatten()
+        .map(|edge| SpanningEdge::new(edge.ivex, edge.jvex, weight_of(&edge.weight)))
+        .collect();
+    kruskal_from_edges(graph.vertices.len(), edges)
+}
+
+/// 朴素 Prim 算法，适合稠密图
+///
+/// 每次在邻接矩阵中扫描出离当前生成树最近的顶点，时间复杂度为 O(V²)。
+/// 图应按无向图存储，即每条边的两个方向都存在于矩阵中。
+/// 图不连通时，从根顶点所在的分量开始，再依次从编号最小的未访问顶点继续，得到生成森林。
+///
+/// # 参数
+/// * `graph` - 邻接矩阵
+/// * `root` - 起始根顶点，None时从顶点0开始
+/// * `weight_of` - 从边上存储的数据中取出权重的闭包
+///
+/// # Panics
+/// 当根顶点索引超出范围时会panic
+pub fn prim_dense<T, W, D, F>(
+    graph: &AdjacencyMatrix<T, W>,
+    root: Option<usize>,
+    weight_of: F,
+) -> MinimumSpanningTree<D>
+where
+    W: Clone,
+    D: Weight,
+    F: Fn(&W) -> D,
+{
+    let n = graph.vertices();
+    if root.is_some_and(|r| r >= n) {
+        panic!("Vertex index out of bounds");
+    }
+
+    let mut in_tree = vec![false; n];
+    // 每个顶点到当前生成树的最小边权，以及这条边在树中的端点
+    let mut lowcost: Vec<Option<D>> = vec![None; n];
+    let mut closest = vec![0; n];
+    let mut edges = Vec::new();
+    let mut total_weight = D::zero();
+    let mut components = 0;
+
+    // 将顶点加入生成树，并用它的出边更新其余顶点的 lowcost
+    let add_to_tree =
+        |u: usize, in_tree: &mut [bool], lowcost: &mut [Option<D>], closest: &mut [usize]| {
+            in_tree[u] = true;
+            for v in 0..n {
+                if in_tree[v] {
+                    continue;
+                }
+                if let Some(edge) = graph.get_edge(u, v) {
+                    let weight = weight_of(edge);
+                    if lowcost[v].is_none_or(|current| weight < current) {
+                        lowcost[v] = Some(weight);
+                        closest[v] = u;
+                    }
+                }
+            }
+        };
+
+    for start in root.into_iter().chain(0..n) {
+        if in_tree[start] {
+            continue;
+        }
+        components += 1;
+        add_to_tree(start, &mut in_tree, &mut lowcost, &mut closest);
+
+        loop {
+            // 找出离生成树最近的顶点
+            let mut nearest: Option<(usize, D)> = None;
+            for v in 0..n {
+                if in_tree[v] {
+                    continue;
+                }
+                if let Some(weight) = lowcost[v]
+                    && nearest.is_none_or(|(_, best)| weight < best)
+                {
+                    nearest = Some((v, weight));
+                }
+            }
+            let Some((v, weight)) = nearest else {
+                break;
+            };
+
+            edges.push(SpanningEdge::new(closest[v], v, weight));
+            total_weight = total_weight + weight;
+            add_to_tree(v, &mut in_tree, &mut lowcost, &mut closest);
+        }
+    }
+
+    MinimumSpanningTree {
+        edges,
+        total_weight,
+        components,
+    }
+}
+
+/// 基于二叉堆的 Prim 算法，适合稀疏图（如 `AdjacencyList`）
+///
+/// 时间复杂度为 O(E log V)。图应按无向图存储，即每条边的两个方向都存在。
+/// 图不连通时的处理方式与 [`prim_dense`] 相同。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor 和 EdgeWeight trait 的图结构
+/// * `vertex_count` - 图中顶点的总数
+/// * `root` - 起始根顶点，None时从顶点0开始
+/// * `weight_of` - 从边上存储的数据中取出权重的闭包
+///
+/// # Panics
+/// 当根顶点索引超出范围时会panic
+pub fn prim<G, D, F>(
+    graph: &G,
+    vertex_count: usize,
+    root: Option<usize>,
+    weight_of: F,
+) -> MinimumSpanningTree<D>
+where
+    G: GraphNeighbor + EdgeWeight,
+    D: Weight,
+    F: Fn(&G::Weight) -> D,
+{
+    if root.is_some_and(|r| r >= vertex_count) {
+        panic!("Vertex index out of bounds");
+    }
+
+    let mut in_tree = vec![false; vertex_count];
+    let mut lowcost: Vec<Option<D>> = vec![None; vertex_count];
+    let mut closest = vec![0; vertex_count];
+    let mut edges = Vec::new();
+    let mut total_weight = D::zero();
+    let mut components = 0;
+    let mut heap = BinaryHeap::new();
+
+    for start in root.into_iter().chain(0..vertex_count) {
+        if in_tree[start] {
+            continue;
+        }
+        components += 1;
+        heap.push(MinScored {
+            weight: D::zero(),
+            vertex: start,
+        });
+
+        while let Some(MinScored { weight, vertex }) = heap.pop() {
+            // 堆中可能残留同一顶点的旧记录，直接跳过
+            if in_tree[vertex] {
+                continue;
+            }
+            in_tree[vertex] = true;
+            if vertex != start {
+                edges.push(SpanningEdge::new(closest[vertex], vertex, weight));
+                total_weight = total_weight + weight;
+            }
+
+            let mut neighbor = graph.first_neighbor(vertex);
+            while let Some(next) = neighbor {
+                if !in_tree[next] {
+                    for edge in graph.edge_weights(vertex, next) {
+                        let weight = weight_of(edge);
+                        if lowcost[next].is_none_or(|current| weight < current) {
+                            lowcost[next] = Some(weight);
+                            closest[next] = vertex;
+                            heap.push(MinScored {
+                                weight,
+                                vertex: next,
+                            });
+                        }
+                    }
+                }
+                neighbor = graph.next_neighbor(vertex, next);
+            }
+        }
+    }
+
+    MinimumSpanningTree {
+        edges,
+        total_weight,
+        components,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{AdjacencyList, AdjacencyMatrix, AdjacencyMultilist};
+
+    /// 测试用的无向图的边: (u, v, 权重)
+    ///
+    /// 最小生成树为 0-1(1), 1-2(2), 2-3(3), 3-4(4)，总权重10
+    const EDGES: [(usize, usize, i32); 7] = [
+        (0, 1, 1),
+        (1, 2, 2),
+        (0, 2, 4),
+        (2, 3, 3),
+        (1, 3, 6),
+        (3, 4, 4),
+        (2, 4, 7),
+    ];
+
+    fn sorted<D>(mst: &MinimumSpanningTree<D>) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = mst.edges.iter().map(|e| (e.u, e.v)).collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let expected = vec![(0, 1), (1, 2), (2, 3), (3, 4)];
+
+        let mut aml = AdjacencyMultilist::<usize, i32>::new();
+        for v in 0..5 {
+            aml.add_vertex(v);
+        }
+        let mut list = AdjacencyList::<usize, i32>::new(5);
+        let mut matrix = AdjacencyMatrix::<usize, i32>::new(5);
+        for (u, v, w) in EDGES {
+            aml.add_edge(u, v, w);
+            list.add_edge(u, v, w);
+            list.add_edge(v, u, w);
+            matrix.add_edge(u, v, Some(w));
+            matrix.add_edge(v, u, Some(w));
+        }
+
+        for mst in [
+            kruskal_multilist(&aml, |w| *w),
+            kruskal(&list, 5, |w| *w),
+            kruskal(&matrix, 5, |w| *w),
+            prim_dense(&matrix, None, |w| *w),
+            prim_dense(&matrix, Some(3), |w| *w),
+            prim(&list, 5, None, |w| *w),
+            prim(&list, 5, Some(4), |w| *w),
+        ] {
+            assert_eq!(sorted(&mst), expected);
+            assert_eq!(mst.total_weight, 10);
+            assert!(mst.is_spanning_tree());
+        }
+    }
+
+    #[test]
+    fn test_kruskal_forest() {
+        // 两个连通分量 {0, 1, 2} 和 {3, 4}，以及孤立顶点5
+        let mut aml = AdjacencyMultilist::<(), f64>::new();
+        for _ in 0..6 {
+            aml.add_vertex(());
+        }
+        aml.add_edge(0, 1, 1.5);
+        aml.add_edge(1, 2, 0.5);
+        aml.add_edge(0, 2, 2.0);
+        aml.add_edge(3, 4, 1.0);
+
+        let mst = kruskal_multilist(&aml, |w| *w);
+        assert_eq!(sorted(&mst), vec![(0, 1), (1, 2), (3, 4)]);
+        assert_eq!(mst.total_weight, 3.0);
+        assert_eq!(mst.components, 3);
+        assert!(!mst.is_spanning_tree());
+    }
+
+    #[test]
+    fn test_prim_forest() {
+        // 两个连通分量 {0, 1, 2} 和 {3, 4}，以及孤立顶点5
+        let mut list = AdjacencyList::<(), f64>::new(6);
+        let mut matrix = AdjacencyMatrix::<(), f64>::new(6);
+        for (u, v, w) in [(0, 1, 1.5), (1, 2, 0.5), (0, 2, 2.0), (3, 4, 1.0)] {
+            list.add_edge(u, v, w);
+            list.add_edge(v, u, w);
+            matrix.add_edge(u, v, Some(w));
+            matrix.add_edge(v, u, Some(w));
+        }
+
+        let dense = prim_dense(&matrix, Some(4), |w| *w);
+        let sparse = prim(&list, 6, Some(4), |w| *w);
+        let reference = kruskal(&list, 6, |w| *w);
+        for mst in [&dense, &sparse] {
+            assert_eq!(sorted(mst), sorted(&reference));
+            assert_eq!(mst.total_weight, reference.total_weight);
+            assert_eq!(mst.components, 3);
+        }
+        // 从根顶点4所在的分量开始
+        assert_eq!(dense.edges[0], SpanningEdge::new(4, 3, 1.0));
+        assert_eq!(sparse.edges[0], SpanningEdge::new(3, 4, 1.0));
+    }
+
+    #[test]
+    fn test_parallel_edges() {
+        // 邻接多重表中 0 - 1 有两条权重不同的平行边，后添加的权重更大
+        let mut aml = AdjacencyMultilist::<(), i32>::new();
+        for _ in 0..3 {
+            aml.add_vertex(());
+        }
+        aml.add_edge(0, 1, 2);
+        aml.add_edge(1, 2, 3);
+        aml.add_edge(0, 1, 7);
+
+        assert_eq!(kruskal(&aml, 3, |w| *w).total_weight, 5);
+        assert_eq!(prim(&aml, 3, Some(0), |w| *w).total_weight, 5);
+        assert_eq!(prim(&aml, 3, Some(2), |w| *w).total_weight, 5);
+    }
+}
@@ -0,0 +1,383 @@
+//! 有向图的强连通分量（Tarjan 和 Kosaraju 算法）
+//!
+//! 两种算法给出的分量编号一致：编号按缩点后DAG的拓扑序排列，
+//! 即缩点图中的边总是从编号小的分量指向编号大的分量。
+
+use super::adjacency_list::AdjacencyList;
+use super::orthogonal_list::OrthogonalList;
+use super::traversal::GraphNeighbor;
+
+/// 强连通分量分解的结果
+#[derive(Debug, Clone)]
+pub struct StronglyConnectedComponents {
+    /// 每个顶点所属的分量编号
+    pub component: Vec<usize>,
+    /// 缩点后的有向无环图
+    ///
+    /// 顶点 `c` 对应编号为 `c` 的分量，顶点数据是分量包含的原图顶点（升序），
+    /// 边的权重是原图中从一个分量指向另一个分量的边的条数，平行边只计一次。
+    pub condensation: AdjacencyList<Vec<usize>, usize>,
+}
+
+impl StronglyConnectedComponents {
+    /// 获取强连通分量的个数
+    pub fn count(&self) -> usize {
+        self.condensation.vertices()
+    }
+
+    /// 获取编号为 `component` 的分量包含的顶点
+    ///
+    /// # Panics
+    /// 当分量编号越界时 panic
+    pub fn members(&self, component: usize) -> &[usize] {
+        self.condensation
+            .get_vertex_data(component)
+            .expect("every component has its members recorded")
+    }
+
+    /// 判断两个顶点是否在同一个强连通分量中
+    pub fn same_component(&self, u: usize, v: usize) -> bool {
+        self.component[u] == self.component[v]
+    }
+}
+
+/// 使用 Tarjan 算法求强连通分量
+///
+/// 一次深度优先搜索，用 low-link 值识别每个分量的根，时间复杂度为 O(V + E)。
+/// 搜索使用显式栈实现，不会因为图太深而栈溢出。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的有向图
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 返回每个顶点的分量编号和缩点后的图
+pub fn tarjan_scc<G>(graph: &G, vertex_count: usize) -> StronglyConnectedComponents
+where
+    G: GraphNeighbor,
+{
+    let mut index: Vec<Option<usize>> = vec![None; vertex_count];
+    let mut low = vec![0; vertex_count];
+    let mut on_stack = vec![false; vertex_count];
+    let mut stack = Vec::new();
+    let mut component = vec![0; vertex_count];
+    let mut next_index = 0;
+    let mut count = 0;
+
+    for root in 0..vertex_count {
+        if index[root].is_some() {
+            continue;
+        }
+
+        // 调用栈中保存 (顶点, 下一个待检查的邻接顶点)
+        index[root] = Some(next_index);
+        low[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        let mut calls = vec![(root, graph.first_neighbor(root))];
+
+        while let Some(top) = calls.last_mut() {
+            let (current, neighbor) = *top;
+            match neighbor {
+                Some(next) => {
+                    top.1 = graph.next_neighbor(current, next);
+                    match index[next] {
+                        None => {
+                            index[next] = Some(next_index);
+                            low[next] = next_index;
+                            next_index += 1;
+                            stack.push(next);
+                            on_stack[next] = true;
+                            calls.push((next, graph.first_neighbor(next)));
+                        }
+                        Some(next_idx) if on_stack[next] => {
+                            low[current] = low[current].min(next_idx);
+                        }
+                        Some(_) => {}
+                    }
+                }
+                None => {
+                    calls.pop();
+                    if let Some(&(parent, _)) = calls.last() {
+                        low[parent] = low[parent].min(low[current]);
+                    }
+                    // current 是分量的根，弹出栈中它之上的所有顶点
+                    if Some(low[current]) == index[current] {
+                        loop {
+                            let member = stack.pop().expect("root is still on the stack");
+                            on_stack[member] = false;
+                            component[member] = count;
+                            if member == current {
+                                break;
+                            }
+                        }
+                        count += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    // Tarjan 按逆拓扑序产生分量，翻转编号使其与 Kosaraju 一致
+    for id in component.iter_mut() {
+        *id = count - 1 - *id;
+    }
+    let condensation = condense(&component, count, |v| neighbors(graph, v));
+    StronglyConnectedComponents {
+        component,
+        condensation,
+    }
+}
+
+/// 使用 Kosaraju 算法求强连通分量
+///
+/// 先在原图上按DFS完成时间排序，再按完成时间的逆序在反向图上做DFS。
+/// 反向图通过一次遍历所有边构建，额外占用 O(V + E) 的空间。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的有向图
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 返回每个顶点的分量编号和缩点后的图
+pub fn kosaraju_scc<G>(graph: &G, vertex_count: usize) -> StronglyConnectedComponents
+where
+    G: GraphNeighbor,
+{
+    let successors: Vec<Vec<usize>> = (0..vertex_count)
+        .map(|vertex| neighbors(graph, vertex).collect())
+        .collect();
+    let mut predecessors = vec![Vec::new(); vertex_count];
+    for (vertex, list) in successors.iter().enumerate() {
+        for &next in list {
+            predecessors[next].push(vertex);
+        }
+    }
+
+    kosaraju_with(
+        vertex_count,
+        |v| successors[v].iter().copied(),
+        |v| predecessors[v].iter().copied(),
+    )
+}
+
+/// 在十字链表上使用 Kosaraju 算法求强连通分量
+///
+/// 十字链表同时维护了出弧和入弧链表，第二遍DFS直接沿入弧进行，不需要构建反向图。
+///
+/// # 参数
+/// * `graph` - 十字链表存储的有向图
+///
+/// # 返回值
+/// 返回每个顶点的分量编号和缩点后的图
+pub fn kosaraju_scc_orthogonal<T, W>(graph: &OrthogonalList<T, W>) -> StronglyConnectedComponents {
+    kosaraju_with(
+        graph.vertices.len(),
+        |v| graph.out_arcs(v).map(|(_, arc)| arc.head_vex),
+        |v| graph.in_arcs(v).map(|(_, arc)| arc.tail_vex),
+    )
+}
+
+/// 依次产出 `vertex` 的所有邻接顶点
+fn neighbors<G>(graph: &G, vertex: usize) -> impl Iterator<Item = usize> + '_
+where
+    G: GraphNeighbor,
+{
+    let mut neighbor = graph.first_neighbor(vertex);
+    std::iter::from_fn(move || {
+        let current = neighbor?;
+        neighbor = graph.next_neighbor(vertex, current);
+        Some(current)
+    })
+}
+
+/// Kosaraju 算法的主体，出边和入边分别由两个闭包给出
+fn kosaraju_with<O, OI, I, II>(
+    vertex_count: usize,
+    successors: O,
+    predecessors: I,
+) -> StronglyConnectedComponents
+where
+    O: Fn(usize) -> OI,
+    OI: Iterator<Item = usize>,
+    I: Fn(usize) -> II,
+    II: Iterator<Item = usize>,
+{
+    // 第一遍：在原图上记录DFS完成顺序
+    let mut visited = vec![false; vertex_count];
+    let mut finished = Vec::with_capacity(vertex_count);
+    for root in 0..vertex_count {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut calls = vec![(root, successors(root))];
+        while let Some((current, iter)) = calls.last_mut() {
+            match iter.find(|&next| !visited[next]) {
+                Some(next) => {
+                    visited[next] = true;
+                    calls.push((next, successors(next)));
+                }
+                None => {
+                    finished.push(*current);
+                    calls.pop();
+                }
+            }
+        }
+    }
+
+    // 第二遍：按完成时间逆序在反向图上DFS，每棵树是一个强连通分量
+    let mut component: Vec<Option<usize>> = vec![None; vertex_count];
+    let mut count = 0;
+    for &root in finished.iter().rev() {
+        if component[root].is_some() {
+            continue;
+        }
+        component[root] = Some(count);
+        let mut stack = vec![root];
+        while let Some(current) = stack.pop() {
+            for prev in predecessors(current) {
+                if component[prev].is_none() {
+                    component[prev] = Some(count);
+                    stack.push(prev);
+                }
+            }
+        }
+        count += 1;
+    }
+
+    let component: Vec<usize> = component
+        .into_iter()
+        .map(|c| c.expect("every vertex is assigned a component"))
+        .collect();
+    let condensation = condense(&component, count, successors);
+    StronglyConnectedComponents {
+        component,
+        condensation,
+    }
+}
+
+/// 根据分量编号构建缩点后的图
+fn condense<O, OI>(
+    component: &[usize],
+    count: usize,
+    successors: O,
+) -> AdjacencyList<Vec<usize>, usize>
+where
+    O: Fn(usize) -> OI,
+    OI: Iterator<Item = usize>,
+{
+    let mut condensation = AdjacencyList::new(count);
+    let mut members = vec![Vec::new(); count];
+    for (vertex, &c) in component.iter().enumerate() {
+        members[c].push(vertex);
+    }
+    for (c, list) in members.into_iter().enumerate() {
+        condensation.set_vertex_data(c, list);
+    }
+
+    for (vertex, &from) in component.iter().enumerate() {
+        let mut previous = None;
+        for next in successors(vertex) {
+            // 十字链表的平行弧在出弧链表中相邻，跳过它们，与 GraphNeighbor 的计数保持一致
+            if previous.replace(next) == Some(next) {
+                continue;
+            }
+            let to = component[next];
+            if from != to {
+                let crossing = condensation.get_edge(from, to).copied().unwrap_or(0);
+                condensation.add_edge(from, to, crossing + 1);
+            }
+        }
+    }
+    condensation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::topological::topological_sort_kahn;
+
+    /// 三个强连通分量: {0, 1, 2}、{3, 4}、{5}
+    ///     0 → 1 → 2 → 0
+    ///     2 → 3 ⇄ 4
+    ///     1 → 4, 4 → 5
+    const EDGES: [(usize, usize); 8] = [
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 3),
+        (1, 4),
+        (4, 5),
+    ];
+
+    fn check(scc: &StronglyConnectedComponents) {
+        assert_eq!(scc.count(), 3);
+        assert_eq!(scc.component, vec![0, 0, 0, 1, 1, 2]);
+        assert_eq!(scc.members(1), &[3, 4]);
+        assert!(scc.same_component(0, 2));
+        assert!(!scc.same_component(2, 3));
+
+        // 缩点后是一条链 0 → 1 → 2，{0,1,2} 到 {3,4} 有两条边
+        let dag = &scc.condensation;
+        assert_eq!(dag.edges(), 2);
+        assert_eq!(dag.get_edge(0, 1), Some(&2));
+        assert_eq!(dag.get_edge(1, 2), Some(&1));
+        assert_eq!(topological_sort_kahn(dag, 3).unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_scc() {
+        let mut list = AdjacencyList::<(), i32>::new(6);
+        let mut ol = OrthogonalList::<(), i32>::new();
+        for _ in 0..6 {
+            ol.add_vertex(());
+        }
+        for (u, v) in EDGES {
+            list.add_edge(u, v, 1);
+            ol.add_edge(u, v, 1);
+        }
+
+        check(&tarjan_scc(&list, 6));
+        check(&kosaraju_scc(&list, 6));
+        check(&kosaraju_scc_orthogonal(&ol));
+    }
+
+    #[test]
+    fn test_scc_parallel_arcs() {
+        // 在十字链表中重复添加弧 2 → 3，缩点图的边权不受影响
+        let mut ol = OrthogonalList::<(), i32>::new();
+        for _ in 0..6 {
+            ol.add_vertex(());
+        }
+        for (u, v) in EDGES {
+            ol.add_edge(u, v, 1);
+        }
+        ol.add_edge(2, 3, 5);
+
+        check(&tarjan_scc(&ol, 6));
+        check(&kosaraju_scc(&ol, 6));
+        check(&kosaraju_scc_orthogonal(&ol));
+    }
+
+    #[test]
+    fn test_scc_dag() {
+        // 无环图中每个顶点自成一个分量
+        let mut list = AdjacencyList::<(), i32>::new(4);
+        list.add_edge(0, 1, 1);
+        list.add_edge(0, 2, 1);
+        list.add_edge(2, 3, 1);
+
+        for scc in [tarjan_scc(&list, 4), kosaraju_scc(&list, 4)] {
+            assert_eq!(scc.count(), 4);
+            assert_eq!(scc.condensation.edges(), 3);
+            // 分量编号是拓扑序
+            for (u, v) in [(0, 1), (0, 2), (2, 3)] {
+                assert!(scc.component[u] < scc.component[v]);
+            }
+        }
+    }
+}
@@ -1,17 +1,26 @@
 //! 压缩对称矩阵存储结构
 
+use super::graph_trait::Graph;
+use super::traversal::{EdgeWeight, GraphNeighbor};
+
 /// 压缩对称矩阵
 /// 用于存储对称矩阵，只保存上三角或下三角部分以节省空间
+///
+/// # 类型参数
+/// * `E` - 元素类型，默认为 `i32`
 #[derive(Debug, Clone)]
-pub struct SymmetricMatrix {
+pub struct SymmetricMatrix<E = i32> {
     /// 矩阵大小 (n x n)
     size: usize,
     /// 压缩存储的元素，只存储下三角部分（包括对角线）
-    elements: Vec<i32>,
+    elements: Vec<E>,
 }
 
-impl SymmetricMatrix {
-    /// 创建一个新的对称矩阵
+impl<E> SymmetricMatrix<E>
+where
+    E: Clone + Default,
+{
+    /// 创建一个新的对称矩阵，所有元素初始化为 `E::default()`
     ///
     /// # 参数
     /// * `size` - 矩阵大小
@@ -23,7 +32,7 @@ impl SymmetricMatrix {
         let capacity = size * (size + 1) / 2;
         SymmetricMatrix {
             size,
-            elements: vec![0; capacity],
+            elements: vec![E::default(); capacity],
         }
     }
 
@@ -37,7 +46,7 @@ impl SymmetricMatrix {
     ///
     /// # Panics
     /// 当输入矩阵不是方阵时会panic
-    pub fn from_matrix(matrix: Vec<Vec<i32>>) -> Self {
+    pub fn from_matrix(matrix: Vec<Vec<E>>) -> Self {
         let size = matrix.len();
 
         // 检查是否为方阵
@@ -52,7 +61,7 @@ impl SymmetricMatrix {
         // 只存储下三角部分
         for i in 0..size {
             for j in 0..=i {
-                result.set(i, j, matrix[i][j]);
+                result.set(i, j, matrix[i][j].clone());
             }
         }
 
@@ -93,7 +102,7 @@ impl SymmetricMatrix {
     ///
     /// # Panics
     /// 当索引超出范围时会panic
-    pub fn set(&mut self, row: usize, col: usize, value: i32) {
+    pub fn set(&mut self, row: usize, col: usize, value: E) {
         let index = self.to_index(row, col);
         self.elements[index] = value;
     }
@@ -109,16 +118,62 @@ impl SymmetricMatrix {
     ///
     /// # Panics
     /// 当索引超出范围时会panic
-    pub fn get(&self, row: usize, col: usize) -> i32 {
+    pub fn get(&self, row: usize, col: usize) -> E {
         let index = self.to_index(row, col);
-        self.elements[index]
+        self.elements[index].clone()
+    }
+
+    /// 获取矩阵元素的引用
+    ///
+    /// # 参数
+    /// * `row` - 行索引
+    /// * `col` - 列索引
+    ///
+    /// # Panics
+    /// 当索引超出范围时会panic
+    pub fn get_ref(&self, row: usize, col: usize) -> &E {
+        let index = self.to_index(row, col);
+        &self.elements[index]
+    }
+
+    /// 矩阵扩展一行一列，新元素初始化为 `E::default()`
+    ///
+    /// 压缩存储按行排列下三角部分，新的一行正好追加在末尾，已有元素不需要移动。
+    ///
+    /// # 返回值
+    /// 返回新增行（列）的索引
+    pub fn expand(&mut self) -> usize {
+        self.elements
+            .extend(std::iter::repeat_n(E::default(), self.size + 1));
+        self.size += 1;
+        self.size - 1
+    }
+
+    /// 删除第 `index` 行和第 `index` 列，矩阵缩小一行一列
+    ///
+    /// 下标大于 `index` 的行（列）依次前移一位。
+    ///
+    /// # Panics
+    /// 当索引超出范围时会panic
+    pub fn shrink(&mut self, index: usize) {
+        if index >= self.size {
+            panic!("Index out of bounds");
+        }
+        // 按行遍历下三角部分，保留行号和列号都不等于 index 的元素
+        let positions = (0..self.size).flat_map(|row| (0..=row).map(move |col| (row, col)));
+        self.elements = positions
+            .zip(std::mem::take(&mut self.elements))
+            .filter(|&((row, col), _)| row != index && col != index)
+            .map(|(_, e)| e)
+            .collect();
+        self.size -= 1;
     }
 
     /// 将压缩矩阵转换为完整的二维向量
     ///
     /// # 返回值
     /// 返回完整的二维向量表示的矩阵
-    pub fn to_matrix(&self) -> Vec<Vec<i32>> {
+    pub fn to_matrix(&self) -> Vec<Vec<E>> {
         let mut matrix = Vec::with_capacity(self.size);
 
         for i in 0..self.size {
@@ -133,6 +188,84 @@ impl SymmetricMatrix {
     }
 }
 
+// 元素为 Option<W> 的对称矩阵可以看作无向图的邻接矩阵，None 表示无边
+impl<W> GraphNeighbor for SymmetricMatrix<Option<W>>
+where
+    W: Clone,
+{
+    fn first_neighbor(&self, vertex: usize) -> Option<usize> {
+        if vertex >= self.size {
+            return None;
+        }
+        (0..self.size).find(|&j| self.get_ref(vertex, j).is_some())
+    }
+
+    fn next_neighbor(&self, vertex: usize, current_neighbor: usize) -> Option<usize> {
+        if vertex >= self.size {
+            return None;
+        }
+        (current_neighbor + 1..self.size).find(|&j| self.get_ref(vertex, j).is_some())
+    }
+}
+
+impl<W> EdgeWeight for SymmetricMatrix<Option<W>>
+where
+    W: Clone,
+{
+    type Weight = W;
+
+    fn edge_weight(&self, from: usize, to: usize) -> Option<&W> {
+        self.get_ref(from, to).as_ref()
+    }
+}
+
+// 压缩对称矩阵不存储顶点数据，顶点数据类型为 ()
+impl<W> Graph for SymmetricMatrix<Option<W>>
+where
+    W: Clone,
+{
+    type VertexData = ();
+
+    fn vertex_count(&self) -> usize {
+        self.size
+    }
+
+    fn edge_count(&self) -> usize {
+        self.elements.iter().filter(|e| e.is_some()).count()
+    }
+
+    fn add_vertex(&mut self, _data: ()) -> usize {
+        self.expand()
+    }
+
+    fn remove_vertex(&mut self, vertex: usize) -> Option<()> {
+        if vertex >= self.size {
+            panic!("Vertex index out of bounds");
+        }
+        self.shrink(vertex);
+        Some(())
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        self.set(from, to, Some(weight));
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) {
+        self.set(from, to, None);
+    }
+
+    fn vertex_data(&self, vertex: usize) -> Option<&()> {
+        (vertex < self.size).then_some(&())
+    }
+
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, &W)> {
+        if vertex >= self.size {
+            panic!("Vertex index out of bounds");
+        }
+        (0..self.size).filter_map(move |j| self.get_ref(vertex, j).as_ref().map(|w| (j, w)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,4 +301,22 @@ mod tests {
 
         assert_eq!(original_matrix, restored_matrix);
     }
+
+    #[test]
+    fn test_shrink_symmetric_matrix() {
+        let mut matrix =
+            SymmetricMatrix::from_matrix(vec![vec![1, 2, 3], vec![2, 4, 5], vec![3, 5, 6]]);
+        matrix.shrink(1);
+        assert_eq!(matrix.size(), 2);
+        assert_eq!(matrix.to_matrix(), vec![vec![1, 3], vec![3, 6]]);
+
+        // 删除顶点后边数随之减少
+        let mut graph = SymmetricMatrix::<Option<i32>>::new(3);
+        Graph::add_edge(&mut graph, 0, 1, 7);
+        Graph::add_edge(&mut graph, 2, 1, 8);
+        Graph::add_edge(&mut graph, 0, 2, 9);
+        assert_eq!(Graph::remove_vertex(&mut graph, 1), Some(()));
+        assert_eq!(Graph::edge_count(&graph), 1);
+        assert_eq!(graph.edge_weight(1, 0), Some(&9));
+    }
 }
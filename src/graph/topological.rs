@@ -0,0 +1,289 @@
+//! 有向无环图（DAG）的拓扑排序
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+
+use super::orthogonal_list::OrthogonalList;
+use super::traversal::{GraphNeighbor, TraversalControl, TraversalVisitor, depth_first_visit_all};
+
+/// 图中存在有向环，无法进行拓扑排序
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// 环上的顶点，按边的方向排列，最后一个顶点有边指回第一个顶点
+    pub cycle: Vec<usize>,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a cycle: {:?}", self.cycle)
+    }
+}
+
+impl Error for CycleError {}
+
+/// 基于DFS的拓扑排序访问器：记录完成顺序，遇到回边时记录环并终止
+#[derive(Default)]
+struct TopoVisitor {
+    /// DFS树中的父顶点
+    parent: Vec<Option<usize>>,
+    /// 顶点的完成顺序
+    finished: Vec<usize>,
+    /// 找到的环
+    cycle: Option<Vec<usize>>,
+}
+
+impl TraversalVisitor for TopoVisitor {
+    fn examine_edge(&mut self, _from: usize, _to: usize) -> TraversalControl {
+        if self.cycle.is_some() {
+            TraversalControl::Stop
+        } else {
+            TraversalControl::Continue
+        }
+    }
+
+    fn tree_edge(&mut self, from: usize, to: usize) {
+        self.parent[to] = Some(from);
+    }
+
+    fn back_edge(&mut self, from: usize, to: usize) {
+        // 回边 from -> to 与树中 to -> ... -> from 的路径构成环
+        let mut cycle = vec![from];
+        let mut current = from;
+        while current != to {
+            current = self.parent[current].expect("back edge target must be an ancestor");
+            cycle.push(current);
+        }
+        cycle.reverse();
+        self.cycle = Some(cycle);
+    }
+
+    fn finish_vertex(&mut self, vertex: usize) {
+        self.finished.push(vertex);
+    }
+}
+
+/// 基于深度优先搜索的拓扑排序
+///
+/// 按顶点完成时间的逆序输出，时间复杂度为 O(V + E)。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 返回拓扑序列；图中有环时返回包含其中一个环的 [`CycleError`]
+pub fn topological_sort_dfs<G>(graph: &G, vertex_count: usize) -> Result<Vec<usize>, CycleError>
+where
+    G: GraphNeighbor,
+{
+    let mut visitor = TopoVisitor {
+        parent: vec![None; vertex_count],
+        ..Default::default()
+    };
+    depth_first_visit_all(graph, &mut visitor, vertex_count);
+
+    if let Some(cycle) = visitor.cycle {
+        return Err(CycleError { cycle });
+    }
+    visitor.finished.reverse();
+    Ok(visitor.finished)
+}
+
+/// 基于 Kahn 算法（反复删除入度为0的顶点）的拓扑排序
+///
+/// 入度为0的顶点按编号顺序入队，时间复杂度为 O(V + E)。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 返回拓扑序列；图中有环时返回包含其中一个环的 [`CycleError`]
+pub fn topological_sort_kahn<G>(graph: &G, vertex_count: usize) -> Result<Vec<usize>, CycleError>
+where
+    G: GraphNeighbor,
+{
+    // 统计每个顶点的入度
+    let mut in_degree = vec![0; vertex_count];
+    for vertex in 0..vertex_count {
+        let mut neighbor = graph.first_neighbor(vertex);
+        while let Some(next) = neighbor {
+            in_degree[next] += 1;
+            neighbor = graph.next_neighbor(vertex, next);
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..vertex_count).filter(|&v| in_degree[v] == 0).collect();
+    let mut order = Vec::with_capacity(vertex_count);
+
+    while let Some(current) = queue.pop_front() {
+        order.push(current);
+        let mut neighbor = graph.first_neighbor(current);
+        while let Some(next) = neighbor {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                queue.push_back(next);
+            }
+            neighbor = graph.next_neighbor(current, next);
+        }
+    }
+
+    if order.len() < vertex_count {
+        // 剩余的顶点中一定有环，用DFS找出其中一个
+        return Err(topological_sort_dfs(graph, vertex_count)
+            .expect_err("vertices left by Kahn's algorithm must contain a cycle"));
+    }
+    Ok(order)
+}
+
+/// 在十字链表上进行 Kahn 拓扑排序
+///
+/// 顶点的入度直接沿入弧链表统计。有环时，未输出的顶点都还有未输出的前驱，
+/// 沿入弧不断回溯前驱即可找到一个环。
+///
+/// # 参数
+/// * `graph` - 十字链表存储的有向图
+///
+/// # 返回值
+/// 返回拓扑序列；图中有环时返回包含其中一个环的 [`CycleError`]
+pub fn topological_sort_orthogonal<T, W>(
+    graph: &OrthogonalList<T, W>,
+) -> Result<Vec<usize>, CycleError> {
+    let n = graph.vertices.len();
+    let mut in_degree: Vec<usize> = (0..n).map(|v| graph.in_degree(v)).collect();
+    let mut queue: VecDeque<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+    let mut done = vec![false; n];
+
+    while let Some(current) = queue.pop_front() {
+        order.push(current);
+        done[current] = true;
+        for (_, arc) in graph.out_arcs(current) {
+            in_degree[arc.head_vex] -= 1;
+            if in_degree[arc.head_vex] == 0 {
+                queue.push_back(arc.head_vex);
+            }
+        }
+    }
+
+    if order.len() == n {
+        return Ok(order);
+    }
+
+    // 从任意未输出的顶点出发，沿入弧回溯未输出的前驱，直到某个顶点重复出现
+    let start = (0..n).find(|&v| !done[v]).expect("some vertex is left");
+    let mut position = vec![None; n];
+    let mut walk = Vec::new();
+    let mut current = start;
+    while position[current].is_none() {
+        position[current] = Some(walk.len());
+        walk.push(current);
+        current = graph
+            .in_arcs(current)
+            .map(|(_, arc)| arc.tail_vex)
+            .find(|&tail| !done[tail])
+            .expect("remaining vertex must have a remaining predecessor");
+    }
+
+    // walk 中从重复顶点开始的一段是沿反方向走出的环
+    let first = position[current].expect("repeated vertex is on the walk");
+    let mut cycle = walk.split_off(first);
+    cycle.reverse();
+    Err(CycleError { cycle })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{AdjacencyList, AdjacencyMatrix};
+
+    /// 课程依赖图: 0→1, 0→2, 1→3, 2→3, 3→4, 5→4
+    const EDGES: [(usize, usize); 6] = [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 4)];
+
+    /// 检查序列是否是合法的拓扑序列
+    fn assert_topological(order: &[usize], edges: &[(usize, usize)], vertex_count: usize) {
+        assert_eq!(order.len(), vertex_count);
+        let mut position = vec![usize::MAX; vertex_count];
+        for (i, &v) in order.iter().enumerate() {
+            position[v] = i;
+        }
+        for &(u, v) in edges {
+            assert!(
+                position[u] < position[v],
+                "edge {u} -> {v} violates {order:?}"
+            );
+        }
+    }
+
+    /// 检查环上相邻顶点之间都有边
+    fn assert_cycle(cycle: &[usize], edges: &[(usize, usize)]) {
+        assert!(!cycle.is_empty());
+        for i in 0..cycle.len() {
+            let edge = (cycle[i], cycle[(i + 1) % cycle.len()]);
+            assert!(
+                edges.contains(&edge),
+                "{edge:?} is not an edge of {cycle:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut list = AdjacencyList::<(), i32>::new(6);
+        let mut matrix = AdjacencyMatrix::<(), i32>::new(6);
+        let mut ol = OrthogonalList::<(), i32>::new();
+        for _ in 0..6 {
+            ol.add_vertex(());
+        }
+        for (u, v) in EDGES {
+            list.add_edge(u, v, 1);
+            matrix.add_edge(u, v, Some(1));
+            ol.add_edge(u, v, 1);
+        }
+
+        assert_eq!(
+            topological_sort_kahn(&matrix, 6).unwrap(),
+            vec![0, 5, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            topological_sort_dfs(&matrix, 6).unwrap(),
+            vec![5, 0, 2, 1, 3, 4]
+        );
+        for order in [
+            topological_sort_kahn(&list, 6).unwrap(),
+            topological_sort_dfs(&list, 6).unwrap(),
+            topological_sort_orthogonal(&ol).unwrap(),
+        ] {
+            assert_topological(&order, &EDGES, 6);
+        }
+    }
+
+    #[test]
+    fn test_topological_sort_cycle() {
+        // 在DAG上加入 4→1，形成环 1→3→4→1
+        let mut edges = EDGES.to_vec();
+        edges.push((4, 1));
+
+        let mut list = AdjacencyList::<(), i32>::new(6);
+        let mut ol = OrthogonalList::<(), i32>::new();
+        for _ in 0..6 {
+            ol.add_vertex(());
+        }
+        for &(u, v) in &edges {
+            list.add_edge(u, v, 1);
+            ol.add_edge(u, v, 1);
+        }
+
+        for err in [
+            topological_sort_kahn(&list, 6).unwrap_err(),
+            topological_sort_dfs(&list, 6).unwrap_err(),
+            topological_sort_orthogonal(&ol).unwrap_err(),
+        ] {
+            assert_cycle(&err.cycle, &edges);
+            let mut sorted = err.cycle.clone();
+            sorted.sort();
+            assert_eq!(sorted, vec![1, 3, 4]);
+        }
+    }
+}
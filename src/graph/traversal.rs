@@ -24,6 +24,95 @@ pub trait GraphNeighbor {
     fn next_neighbor(&self, vertex: usize, current_neighbor: usize) -> Option<usize>;
 }
 
+/// 提供获取有向图入邻接顶点（即以该顶点为弧头的弧的弧尾）的能力
+///
+/// 与 [`GraphNeighbor`] 的约定相同，通过 [`Reversed`] 可以让所有遍历算法沿反向弧进行。
+pub trait GraphInNeighbor {
+    /// 获取指定顶点的第一个入邻接顶点
+    ///
+    /// # 参数
+    /// * `vertex` - 顶点索引
+    ///
+    /// # 返回值
+    /// 返回第一个入邻接顶点的索引，如果没有入邻接顶点返回None
+    fn first_in_neighbor(&self, vertex: usize) -> Option<usize>;
+
+    /// 获取指定顶点相对于当前入邻接顶点的下一个入邻接顶点
+    ///
+    /// # 参数
+    /// * `vertex` - 顶点索引
+    /// * `current_neighbor` - 当前入邻接顶点的索引
+    ///
+    /// # 返回值
+    /// 返回下一个入邻接顶点的索引，如果没有更多入邻接顶点返回None
+    fn next_in_neighbor(&self, vertex: usize, current_neighbor: usize) -> Option<usize>;
+}
+
+/// 反向图视图：把入邻接顶点当作邻接顶点，不复制任何数据
+///
+/// 例如 `breadth_first_search(&Reversed(&graph), v, ...)` 得到的是所有能到达 `v` 的顶点。
+#[derive(Debug, Clone, Copy)]
+pub struct Reversed<'a, G>(pub &'a G);
+
+impl<G> GraphNeighbor for Reversed<'_, G>
+where
+    G: GraphInNeighbor,
+{
+    fn first_neighbor(&self, vertex: usize) -> Option<usize> {
+        self.0.first_in_neighbor(vertex)
+    }
+
+    fn next_neighbor(&self, vertex: usize, current_neighbor: usize) -> Option<usize> {
+        self.0.next_in_neighbor(vertex, current_neighbor)
+    }
+}
+
+/// 在可能含有重复顶点的邻接序列中查找 `current` 之后的下一个邻接顶点
+///
+/// 链式存储允许平行边，同一个邻接顶点可能出现多次。十字链表和邻接多重表插入平行边时
+/// 把它放在已有的同向边旁边，重复的顶点总是连续出现，因此只需从 `current` 所在的位置
+/// 向后跳过与它相同的顶点，就能保证 `first_neighbor` / `next_neighbor` 每个邻接顶点只产出一次。
+pub(crate) fn next_distinct_neighbor<I>(neighbors: I, current: usize) -> Option<usize>
+where
+    I: Iterator<Item = usize>,
+{
+    neighbors
+        .skip_while(|&v| v != current)
+        .find(|&v| v != current)
+}
+
+/// 提供查询边权重的能力，配合 [`GraphNeighbor`] 供带权图算法使用
+pub trait EdgeWeight {
+    /// 边的权重类型
+    type Weight;
+
+    /// 获取边 `from -> to` 的权重
+    ///
+    /// # 参数
+    /// * `from` - 起始顶点
+    /// * `to` - 终止顶点
+    ///
+    /// # 返回值
+    /// 返回边的权重，边不存在时返回None；存在平行边时返回其中一条的权重
+    fn edge_weight(&self, from: usize, to: usize) -> Option<&Self::Weight>;
+
+    /// 获取所有边 `from -> to` 的权重
+    ///
+    /// 带权图算法通过这个方法处理平行边：每条平行边都被当作一条独立的边。
+    /// 默认实现只产出 [`edge_weight`](Self::edge_weight) 的结果，
+    /// 允许平行边的存储结构（十字链表、邻接多重表）会覆盖它。
+    ///
+    /// # 参数
+    /// * `from` - 起始顶点
+    /// * `to` - 终止顶点
+    ///
+    /// # 返回值
+    /// 返回产出每条边权重的迭代器，边不存在时为空
+    fn edge_weights(&self, from: usize, to: usize) -> impl Iterator<Item = &Self::Weight> {
+        self.edge_weight(from, to).into_iter()
+    }
+}
+
 /// 顶点访问器，用于在遍历时访问顶点
 pub trait VertexVisitor {
     /// 访问一个顶点
@@ -33,6 +122,91 @@ pub trait VertexVisitor {
     fn visit(&mut self, vertex: usize);
 }
 
+/// 访问器对遍历过程的控制指令
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TraversalControl {
+    /// 继续正常遍历
+    #[default]
+    Continue,
+    /// 剪枝：在 `discover_vertex` 中返回时不再展开该顶点的邻接边，
+    /// 在 `examine_edge` 中返回时跳过这条边
+    Prune,
+    /// 立即终止整个遍历，之后不会再产生任何事件
+    Stop,
+}
+
+/// 遍历事件访问器，在遍历过程中接收完整的顶点和边事件
+///
+/// 所有方法都有默认实现，只需覆盖关心的事件即可。
+/// `discover_vertex` 和 `examine_edge` 返回 [`TraversalControl`]，
+/// 可以借此在找到目标后提前终止遍历，或者跳过不需要的分支。
+/// 借助这些事件可以在不修改遍历代码的前提下实现环检测、边分类、时间戳等功能。
+///
+/// 边的分类规则：
+/// - 树边：目标顶点第一次被发现时经过的边
+/// - 回边：目标顶点是当前顶点在遍历树中的祖先（包括自环），只有深度优先搜索报告
+/// - 前向边或横叉边：其余的非树边；广度优先搜索把所有非树边都报告为这一类
+pub trait TraversalVisitor {
+    /// 顶点第一次被发现
+    ///
+    /// 返回 `Prune` 时该顶点不会被展开，并立即完成（`finish_vertex`）
+    fn discover_vertex(&mut self, _vertex: usize) -> TraversalControl {
+        TraversalControl::Continue
+    }
+
+    /// 检查一条边 `from -> to`，在对该边分类之前调用
+    ///
+    /// 返回 `Prune` 时这条边既不分类也不沿其深入
+    fn examine_edge(&mut self, _from: usize, _to: usize) -> TraversalControl {
+        TraversalControl::Continue
+    }
+
+    /// 边 `from -> to` 是遍历树中的树边
+    fn tree_edge(&mut self, _from: usize, _to: usize) {}
+
+    /// 边 `from -> to` 是回边
+    fn back_edge(&mut self, _from: usize, _to: usize) {}
+
+    /// 边 `from -> to` 是前向边或横叉边
+    fn forward_or_cross_edge(&mut self, _from: usize, _to: usize) {}
+
+    /// 顶点的所有邻接边都已处理完毕
+    fn finish_vertex(&mut self, _vertex: usize) {}
+
+    /// 全图遍历中，从根顶点 `root` 开始一个新的连通分量，在发现根顶点之前调用
+    fn start_component(&mut self, _component: usize, _root: usize) {}
+
+    /// 全图遍历中，一个连通分量的所有顶点都已遍历完毕
+    fn finish_component(&mut self, _component: usize) {}
+}
+
+/// 将 [`VertexVisitor`] 适配为 [`TraversalVisitor`]
+///
+/// 只把 `discover_vertex` 事件转发给内部访问器的 `visit` 方法，
+/// 因此原有的 `CollectVisitor`、`PrintVisitor` 可以直接用于新的遍历函数。
+pub struct VisitorAdapter<'a, V>(pub &'a mut V);
+
+impl<V> TraversalVisitor for VisitorAdapter<'_, V>
+where
+    V: VertexVisitor,
+{
+    fn discover_vertex(&mut self, vertex: usize) -> TraversalControl {
+        self.0.visit(vertex);
+        TraversalControl::Continue
+    }
+}
+
+/// 遍历过程中顶点的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    /// 尚未被发现
+    White,
+    /// 已被发现但尚未完成
+    Gray,
+    /// 已完成
+    Black,
+}
+
 /// 从指定顶点开始进行广度优先搜索
 ///
 /// # 参数
@@ -49,27 +223,619 @@ where
     G: GraphNeighbor,
     V: VertexVisitor,
 {
-    // 访问标记数组
-    let mut visited = vec![false; vertex_count];
+    breadth_first_visit(graph, start, &mut VisitorAdapter(visitor), vertex_count);
+}
+
+/// 从一组起始顶点同时开始进行广度优先搜索（多源BFS）
+///
+/// 所有起始顶点按给定顺序最先被访问，之后按与最近起始顶点的距离逐层访问其余顶点。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `sources` - 起始顶点索引，重复的顶点只访问一次
+/// * `visitor` - 实现了 VertexVisitor trait 的访问器
+/// * `vertex_count` - 图中顶点的总数
+pub fn breadth_first_search_multi<G, V>(
+    graph: &G,
+    sources: &[usize],
+    visitor: &mut V,
+    vertex_count: usize,
+) where
+    G: GraphNeighbor,
+    V: VertexVisitor,
+{
+    breadth_first_visit_multi(graph, sources, &mut VisitorAdapter(visitor), vertex_count);
+}
+
+/// 从指定顶点开始进行广度优先搜索，并向访问器报告完整的遍历事件
+///
+/// 顶点在入队时被发现（`discover_vertex`），出队并处理完所有邻接边后完成（`finish_vertex`）。
+/// 判断非树边是否指向祖先需要沿BFS树回溯，为保持 O(V + E) 的时间复杂度，
+/// 广度优先搜索不报告回边，所有非树边都通过 `forward_or_cross_edge` 报告。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `start` - 起始顶点索引
+/// * `visitor` - 实现了 TraversalVisitor trait 的访问器
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 访问器要求终止时返回 `TraversalControl::Stop`，否则返回 `TraversalControl::Continue`
+pub fn breadth_first_visit<G, V>(
+    graph: &G,
+    start: usize,
+    visitor: &mut V,
+    vertex_count: usize,
+) -> TraversalControl
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
+    // 顶点状态数组
+    let mut color = vec![Color::White; vertex_count];
+    bfs_from(graph, &[start], visitor, &mut color)
+}
+
+/// 从一组起始顶点同时开始进行广度优先搜索，并向访问器报告完整的遍历事件
+///
+/// 每个起始顶点都是BFS森林中一棵树的根，依次被发现后再统一出队展开。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `sources` - 起始顶点索引，重复的顶点只访问一次
+/// * `visitor` - 实现了 TraversalVisitor trait 的访问器
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 访问器要求终止时返回 `TraversalControl::Stop`，否则返回 `TraversalControl::Continue`
+pub fn breadth_first_visit_multi<G, V>(
+    graph: &G,
+    sources: &[usize],
+    visitor: &mut V,
+    vertex_count: usize,
+) -> TraversalControl
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
+    let mut color = vec![Color::White; vertex_count];
+    bfs_from(graph, sources, visitor, &mut color)
+}
+
+/// BFS的核心过程，从一组起始顶点出发，只访问尚未被发现的顶点
+///
+/// 顶点状态数组由调用者持有，因此可以多次调用以遍历整个图。
+fn bfs_from<G, V>(
+    graph: &G,
+    sources: &[usize],
+    visitor: &mut V,
+    color: &mut [Color],
+) -> TraversalControl
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
     // 使用队列实现BFS
     let mut queue = VecDeque::new();
 
     // 访问起始顶点
-    visited[start] = true;
-    visitor.visit(start);
-    queue.push_back(start);
+    for &source in sources {
+        if color[source] == Color::White
+            && bfs_discover(source, visitor, color, &mut queue) == TraversalControl::Stop
+        {
+            return TraversalControl::Stop;
+        }
+    }
 
     while let Some(current) = queue.pop_front() {
         // 遍历所有邻接顶点
         let mut neighbor = graph.first_neighbor(current);
         while let Some(next) = neighbor {
-            if !visited[next] {
-                visited[next] = true;
-                visitor.visit(next);
-                queue.push_back(next);
+            match visitor.examine_edge(current, next) {
+                TraversalControl::Stop => return TraversalControl::Stop,
+                TraversalControl::Prune => {}
+                TraversalControl::Continue => {
+                    if color[next] == Color::White {
+                        visitor.tree_edge(current, next);
+                        if bfs_discover(next, visitor, color, &mut queue) == TraversalControl::Stop
+                        {
+                            return TraversalControl::Stop;
+                        }
+                    } else {
+                        visitor.forward_or_cross_edge(current, next);
+                    }
+                }
             }
             neighbor = graph.next_neighbor(current, next);
         }
+        color[current] = Color::Black;
+        visitor.finish_vertex(current);
+    }
+    TraversalControl::Continue
+}
+
+/// BFS中发现一个顶点：通知访问器，并根据返回的控制指令决定是否入队
+fn bfs_discover<V>(
+    vertex: usize,
+    visitor: &mut V,
+    color: &mut [Color],
+    queue: &mut VecDeque<usize>,
+) -> TraversalControl
+where
+    V: TraversalVisitor,
+{
+    color[vertex] = Color::Gray;
+    let control = visitor.discover_vertex(vertex);
+    match control {
+        TraversalControl::Continue => queue.push_back(vertex),
+        // 被剪枝的顶点不再展开，直接完成
+        TraversalControl::Prune => {
+            color[vertex] = Color::Black;
+            visitor.finish_vertex(vertex);
+        }
+        TraversalControl::Stop => {}
+    }
+    control
+}
+
+/// 广度优先搜索树，记录每个顶点到起点的跳数和在BFS树中的父顶点
+///
+/// 多源BFS得到的是一片森林，每个顶点的距离和父顶点都相对于离它最近的起点。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BfsTree {
+    /// 起始顶点，多源BFS中为第一个起点
+    pub start: usize,
+    /// 所有起始顶点，按给出的顺序排列，重复的起点只保留一次
+    pub sources: Vec<usize>,
+    /// 每个顶点到最近起点的最短跳数，不可达的顶点为None
+    pub distances: Vec<Option<usize>>,
+    /// 每个顶点在BFS树中的父顶点，起点和不可达的顶点为None
+    pub parents: Vec<Option<usize>>,
+    /// 每个顶点是从哪个起点到达的，不可达的顶点为None
+    pub source_of: Vec<Option<usize>>,
+}
+
+impl BfsTree {
+    /// 重建从起点到目标顶点的最短（无权）路径
+    ///
+    /// 多源BFS中路径的起点是离目标最近的那个起点，即 `source_of[target]`。
+    ///
+    /// # 参数
+    /// * `target` - 目标顶点索引
+    ///
+    /// # 返回值
+    /// 返回从起点到目标顶点依次经过的顶点（包含两端），目标不可达时返回None
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distances.get(target).copied().flatten()?;
+
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(parent) = self.parents[current] {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// 构建BFS树的访问器，在树边上记录父顶点和距离
+struct BfsTreeVisitor<'a> {
+    tree: &'a mut BfsTree,
+}
+
+impl TraversalVisitor for BfsTreeVisitor<'_> {
+    fn tree_edge(&mut self, from: usize, to: usize) {
+        self.tree.parents[to] = Some(from);
+        self.tree.distances[to] = self.tree.distances[from].map(|d| d + 1);
+        self.tree.source_of[to] = self.tree.source_of[from];
+    }
+}
+
+/// 从指定顶点开始进行广度优先搜索，返回每个顶点的跳数和BFS树中的父顶点
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `start` - 起始顶点索引
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 返回BFS树，可通过 [`BfsTree::path_to`] 重建最短路径
+pub fn breadth_first_tree<G>(graph: &G, start: usize, vertex_count: usize) -> BfsTree
+where
+    G: GraphNeighbor,
+{
+    breadth_first_tree_multi(graph, &[start], vertex_count)
+}
+
+/// 从一组起始顶点同时开始进行广度优先搜索，返回每个顶点到最近起点的跳数、
+/// BFS树中的父顶点以及所属的起点
+///
+/// 适用于“到最近设施的距离”一类的查询，只需一次遍历。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `sources` - 起始顶点索引
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 返回BFS森林，可通过 [`BfsTree::path_to`] 重建从最近起点出发的最短路径
+///
+/// # Panics
+/// 当 `sources` 为空或顶点索引超出范围时会panic
+pub fn breadth_first_tree_multi<G>(graph: &G, sources: &[usize], vertex_count: usize) -> BfsTree
+where
+    G: GraphNeighbor,
+{
+    if sources.is_empty() {
+        panic!("At least one source vertex is required");
+    }
+    let mut tree = BfsTree {
+        start: sources[0],
+        sources: Vec::with_capacity(sources.len()),
+        distances: vec![None; vertex_count],
+        parents: vec![None; vertex_count],
+        source_of: vec![None; vertex_count],
+    };
+    for &source in sources {
+        if tree.source_of[source].is_none() {
+            tree.sources.push(source);
+        }
+        tree.distances[source] = Some(0);
+        tree.source_of[source] = Some(source);
+    }
+
+    breadth_first_visit_multi(
+        graph,
+        sources,
+        &mut BfsTreeVisitor { tree: &mut tree },
+        vertex_count,
+    );
+    tree
+}
+
+/// 从指定顶点开始进行深度优先搜索（递归实现）
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `start` - 起始顶点索引
+/// * `visitor` - 实现了 VertexVisitor trait 的访问器
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 泛型参数
+/// * `G` - 图类型，必须实现 GraphNeighbor trait
+/// * `V` - 访问器类型，必须实现 VertexVisitor trait
+pub fn depth_first_search<G, V>(graph: &G, start: usize, visitor: &mut V, vertex_count: usize)
+where
+    G: GraphNeighbor,
+    V: VertexVisitor,
+{
+    depth_first_visit(graph, start, &mut VisitorAdapter(visitor), vertex_count);
+}
+
+/// 从指定顶点开始进行深度优先搜索（非递归实现，使用显式栈）
+///
+/// 访问顺序与递归版本 [`depth_first_search`] 完全一致，
+/// 但不会因为图过深而导致调用栈溢出。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `start` - 起始顶点索引
+/// * `visitor` - 实现了 VertexVisitor trait 的访问器
+/// * `vertex_count` - 图中顶点的总数
+pub fn depth_first_search_iterative<G, V>(
+    graph: &G,
+    start: usize,
+    visitor: &mut V,
+    vertex_count: usize,
+) where
+    G: GraphNeighbor,
+    V: VertexVisitor,
+{
+    depth_first_visit_iterative(graph, start, &mut VisitorAdapter(visitor), vertex_count);
+}
+
+/// 从指定顶点开始进行深度优先搜索（递归实现），并向访问器报告完整的遍历事件
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `start` - 起始顶点索引
+/// * `visitor` - 实现了 TraversalVisitor trait 的访问器
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 访问器要求终止时返回 `TraversalControl::Stop`，否则返回 `TraversalControl::Continue`
+pub fn depth_first_visit<G, V>(
+    graph: &G,
+    start: usize,
+    visitor: &mut V,
+    vertex_count: usize,
+) -> TraversalControl
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
+    // 顶点状态数组
+    let mut color = vec![Color::White; vertex_count];
+    dfs_recursive(graph, start, visitor, &mut color)
+}
+
+/// 递归DFS的辅助函数，返回 `Stop` 表示整个遍历需要终止
+fn dfs_recursive<G, V>(
+    graph: &G,
+    vertex: usize,
+    visitor: &mut V,
+    color: &mut [Color],
+) -> TraversalControl
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
+    color[vertex] = Color::Gray;
+    match visitor.discover_vertex(vertex) {
+        TraversalControl::Stop => return TraversalControl::Stop,
+        // 被剪枝的顶点不再展开，直接完成
+        TraversalControl::Prune => {
+            color[vertex] = Color::Black;
+            visitor.finish_vertex(vertex);
+            return TraversalControl::Continue;
+        }
+        TraversalControl::Continue => {}
+    }
+
+    // 依次检查每条邻接边，对未发现的顶点递归
+    let mut neighbor = graph.first_neighbor(vertex);
+    while let Some(next) = neighbor {
+        match visitor.examine_edge(vertex, next) {
+            TraversalControl::Stop => return TraversalControl::Stop,
+            TraversalControl::Prune => {}
+            TraversalControl::Continue => match color[next] {
+                Color::White => {
+                    visitor.tree_edge(vertex, next);
+                    if dfs_recursive(graph, next, visitor, color) == TraversalControl::Stop {
+                        return TraversalControl::Stop;
+                    }
+                }
+                Color::Gray => visitor.back_edge(vertex, next),
+                Color::Black => visitor.forward_or_cross_edge(vertex, next),
+            },
+        }
+        neighbor = graph.next_neighbor(vertex, next);
+    }
+
+    color[vertex] = Color::Black;
+    visitor.finish_vertex(vertex);
+    TraversalControl::Continue
+}
+
+/// 从指定顶点开始进行深度优先搜索（非递归实现），并向访问器报告完整的遍历事件
+///
+/// 事件顺序与递归版本 [`depth_first_visit`] 完全一致。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `start` - 起始顶点索引
+/// * `visitor` - 实现了 TraversalVisitor trait 的访问器
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 访问器要求终止时返回 `TraversalControl::Stop`，否则返回 `TraversalControl::Continue`
+pub fn depth_first_visit_iterative<G, V>(
+    graph: &G,
+    start: usize,
+    visitor: &mut V,
+    vertex_count: usize,
+) -> TraversalControl
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
+    // 顶点状态数组
+    let mut color = vec![Color::White; vertex_count];
+    dfs_iterative(graph, start, visitor, &mut color)
+}
+
+/// 非递归DFS的核心过程，只访问尚未被发现的顶点
+fn dfs_iterative<G, V>(
+    graph: &G,
+    start: usize,
+    visitor: &mut V,
+    color: &mut [Color],
+) -> TraversalControl
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
+    // 栈中保存 (顶点, 下一个待检查的邻接顶点)，模拟递归时的现场
+    let mut stack = Vec::new();
+
+    if dfs_discover(graph, start, visitor, color, &mut stack) == TraversalControl::Stop {
+        return TraversalControl::Stop;
+    }
+
+    while let Some(top) = stack.last_mut() {
+        let (current, neighbor) = *top;
+        match neighbor {
+            Some(next) => {
+                // 先推进当前顶点的邻接游标，再决定是否深入
+                top.1 = graph.next_neighbor(current, next);
+                match visitor.examine_edge(current, next) {
+                    TraversalControl::Stop => return TraversalControl::Stop,
+                    TraversalControl::Prune => {}
+                    TraversalControl::Continue => match color[next] {
+                        Color::White => {
+                            visitor.tree_edge(current, next);
+                            if dfs_discover(graph, next, visitor, color, &mut stack)
+                                == TraversalControl::Stop
+                            {
+                                return TraversalControl::Stop;
+                            }
+                        }
+                        Color::Gray => visitor.back_edge(current, next),
+                        Color::Black => visitor.forward_or_cross_edge(current, next),
+                    },
+                }
+            }
+            // 所有邻接顶点都已检查完毕，回溯
+            None => {
+                stack.pop();
+                color[current] = Color::Black;
+                visitor.finish_vertex(current);
+            }
+        }
+    }
+    TraversalControl::Continue
+}
+
+/// 非递归DFS中发现一个顶点：通知访问器，并根据返回的控制指令决定是否入栈
+fn dfs_discover<G, V>(
+    graph: &G,
+    vertex: usize,
+    visitor: &mut V,
+    color: &mut [Color],
+    stack: &mut Vec<(usize, Option<usize>)>,
+) -> TraversalControl
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
+    color[vertex] = Color::Gray;
+    let control = visitor.discover_vertex(vertex);
+    match control {
+        TraversalControl::Continue => stack.push((vertex, graph.first_neighbor(vertex))),
+        // 被剪枝的顶点不再展开，直接完成
+        TraversalControl::Prune => {
+            color[vertex] = Color::Black;
+            visitor.finish_vertex(vertex);
+        }
+        TraversalControl::Stop => {}
+    }
+    control
+}
+
+/// 对整个图进行广度优先搜索，依次从每个尚未被发现的顶点重新开始
+///
+/// 每次重新开始时调用访问器的 `start_component`，该次遍历结束后调用 `finish_component`。
+/// 对于无向图（每条边双向存储），每次遍历得到的就是一个连通分量；
+/// 对于有向图，得到的是从各个根顶点出发的可达区域，编号取决于根顶点的选择顺序。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `visitor` - 实现了 TraversalVisitor trait 的访问器
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 返回每个顶点所属的分量编号（从0开始）；访问器提前终止时，未被发现的顶点为None
+pub fn breadth_first_visit_all<G, V>(
+    graph: &G,
+    visitor: &mut V,
+    vertex_count: usize,
+) -> Vec<Option<usize>>
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
+    let mut color = vec![Color::White; vertex_count];
+    visit_all_components(visitor, vertex_count, |root, labeler| {
+        bfs_from(graph, &[root], labeler, &mut color)
+    })
+}
+
+/// 对整个图进行深度优先搜索，依次从每个尚未被发现的顶点重新开始
+///
+/// 使用显式栈实现，事件顺序与 [`depth_first_visit`] 一致；
+/// 分量的划分规则与 [`breadth_first_visit_all`] 相同。
+///
+/// # 参数
+/// * `graph` - 实现了 GraphNeighbor trait 的图结构
+/// * `visitor` - 实现了 TraversalVisitor trait 的访问器
+/// * `vertex_count` - 图中顶点的总数
+///
+/// # 返回值
+/// 返回每个顶点所属的分量编号（从0开始）；访问器提前终止时，未被发现的顶点为None
+pub fn depth_first_visit_all<G, V>(
+    graph: &G,
+    visitor: &mut V,
+    vertex_count: usize,
+) -> Vec<Option<usize>>
+where
+    G: GraphNeighbor,
+    V: TraversalVisitor,
+{
+    let mut color = vec![Color::White; vertex_count];
+    visit_all_components(visitor, vertex_count, |root, labeler| {
+        dfs_iterative(graph, root, labeler, &mut color)
+    })
+}
+
+/// 全图遍历的公共流程：按顶点编号依次选取未被发现的顶点作为根，
+/// 调用 `traverse` 完成一次遍历，并记录每个顶点的分量编号
+fn visit_all_components<V, F>(
+    visitor: &mut V,
+    vertex_count: usize,
+    mut traverse: F,
+) -> Vec<Option<usize>>
+where
+    V: TraversalVisitor,
+    F: FnMut(usize, &mut ComponentLabeler<'_, V>) -> TraversalControl,
+{
+    let mut labeler = ComponentLabeler {
+        inner: visitor,
+        component: 0,
+        labels: vec![None; vertex_count],
+    };
+
+    for root in 0..vertex_count {
+        if labeler.labels[root].is_some() {
+            continue;
+        }
+        let component = labeler.component;
+        labeler.inner.start_component(component, root);
+        if traverse(root, &mut labeler) == TraversalControl::Stop {
+            break;
+        }
+        labeler.inner.finish_component(component);
+        labeler.component += 1;
+    }
+    labeler.labels
+}
+
+/// 为发现的顶点记录分量编号，并把所有事件转发给内部访问器
+struct ComponentLabeler<'a, V> {
+    inner: &'a mut V,
+    /// 当前分量编号
+    component: usize,
+    /// 每个顶点的分量编号
+    labels: Vec<Option<usize>>,
+}
+
+impl<V> TraversalVisitor for ComponentLabeler<'_, V>
+where
+    V: TraversalVisitor,
+{
+    fn discover_vertex(&mut self, vertex: usize) -> TraversalControl {
+        self.labels[vertex] = Some(self.component);
+        self.inner.discover_vertex(vertex)
+    }
+
+    fn examine_edge(&mut self, from: usize, to: usize) -> TraversalControl {
+        self.inner.examine_edge(from, to)
+    }
+
+    fn tree_edge(&mut self, from: usize, to: usize) {
+        self.inner.tree_edge(from, to);
+    }
+
+    fn back_edge(&mut self, from: usize, to: usize) {
+        self.inner.back_edge(from, to);
+    }
+
+    fn forward_or_cross_edge(&mut self, from: usize, to: usize) {
+        self.inner.forward_or_cross_edge(from, to);
+    }
+
+    fn finish_vertex(&mut self, vertex: usize) {
+        self.inner.finish_vertex(vertex);
     }
 }
 
@@ -94,3 +860,326 @@ impl VertexVisitor for PrintVisitor {
         println!("访问顶点: {}", vertex);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyList;
+
+    /// 记录所有事件的访问器
+    #[derive(Debug, Default)]
+    struct EventVisitor {
+        discovered: Vec<usize>,
+        finished: Vec<usize>,
+        tree_edges: Vec<(usize, usize)>,
+        back_edges: Vec<(usize, usize)>,
+        other_edges: Vec<(usize, usize)>,
+        examined: usize,
+        components: Vec<(usize, usize)>,
+        finished_components: Vec<usize>,
+    }
+
+    impl TraversalVisitor for EventVisitor {
+        fn discover_vertex(&mut self, vertex: usize) -> TraversalControl {
+            self.discovered.push(vertex);
+            TraversalControl::Continue
+        }
+
+        fn examine_edge(&mut self, _from: usize, _to: usize) -> TraversalControl {
+            self.examined += 1;
+            TraversalControl::Continue
+        }
+
+        fn tree_edge(&mut self, from: usize, to: usize) {
+            self.tree_edges.push((from, to));
+        }
+
+        fn back_edge(&mut self, from: usize, to: usize) {
+            self.back_edges.push((from, to));
+        }
+
+        fn forward_or_cross_edge(&mut self, from: usize, to: usize) {
+            self.other_edges.push((from, to));
+        }
+
+        fn finish_vertex(&mut self, vertex: usize) {
+            self.finished.push(vertex);
+        }
+
+        fn start_component(&mut self, component: usize, root: usize) {
+            self.components.push((component, root));
+        }
+
+        fn finish_component(&mut self, component: usize) {
+            self.finished_components.push(component);
+        }
+    }
+
+    /// 构造测试用的有向图
+    ///
+    /// 边: 0→1, 0→2, 1→3, 1→4, 2→1, 3→4, 4→2，其中 1→4→2→1 构成环
+    fn sample_graph() -> AdjacencyList<(), i32> {
+        let mut graph = AdjacencyList::new(5);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(0, 2, 1);
+        graph.add_edge(1, 3, 1);
+        graph.add_edge(1, 4, 1);
+        graph.add_edge(2, 1, 1);
+        graph.add_edge(3, 4, 1);
+        graph.add_edge(4, 2, 1);
+        graph
+    }
+
+    #[test]
+    fn test_dfs_edge_classification() {
+        let graph = sample_graph();
+
+        let mut visitor = EventVisitor::default();
+        depth_first_visit(&graph, 0, &mut visitor, 5);
+
+        assert_eq!(visitor.discovered, vec![0, 1, 3, 4, 2]);
+        assert_eq!(visitor.finished, vec![2, 4, 3, 1, 0]);
+        assert_eq!(visitor.tree_edges, vec![(0, 1), (1, 3), (3, 4), (4, 2)]);
+        // 2 → 1 指向栈中的祖先，构成环
+        assert_eq!(visitor.back_edges, vec![(2, 1)]);
+        // 1 → 4 是前向边，0 → 2 是前向边
+        assert_eq!(visitor.other_edges, vec![(1, 4), (0, 2)]);
+        assert_eq!(visitor.examined, 7);
+
+        // 非递归版本的事件序列应与递归版本一致
+        let mut iterative = EventVisitor::default();
+        depth_first_visit_iterative(&graph, 0, &mut iterative, 5);
+        assert_eq!(iterative.discovered, visitor.discovered);
+        assert_eq!(iterative.finished, visitor.finished);
+        assert_eq!(iterative.tree_edges, visitor.tree_edges);
+        assert_eq!(iterative.back_edges, visitor.back_edges);
+        assert_eq!(iterative.other_edges, visitor.other_edges);
+    }
+
+    #[test]
+    fn test_bfs_edge_classification() {
+        let graph = sample_graph();
+
+        let mut visitor = EventVisitor::default();
+        breadth_first_visit(&graph, 0, &mut visitor, 5);
+
+        assert_eq!(visitor.discovered, vec![0, 1, 2, 3, 4]);
+        assert_eq!(visitor.finished, vec![0, 1, 2, 3, 4]);
+        assert_eq!(visitor.tree_edges, vec![(0, 1), (0, 2), (1, 3), (1, 4)]);
+        // BFS不报告回边
+        assert!(visitor.back_edges.is_empty());
+        assert_eq!(visitor.other_edges, vec![(2, 1), (3, 4), (4, 2)]);
+        assert_eq!(visitor.examined, 7);
+    }
+
+    #[test]
+    fn test_bfs_deep_graph() {
+        // 一条很长的链 0 → 1 → ... → n-1，每个顶点还有一条指回0的边和一个自环。
+        // 非树边都指向很深的祖先，遍历仍应在线性时间内完成
+        let n = 50_000;
+        let mut graph = AdjacencyList::<(), i32>::new(n);
+        for v in 0..n {
+            if v + 1 < n {
+                graph.add_edge(v, v + 1, 1);
+            }
+            graph.add_edge(v, 0, 1);
+            graph.add_edge(v, v, 1);
+        }
+
+        let mut visitor = EventVisitor::default();
+        breadth_first_visit(&graph, 0, &mut visitor, n);
+        assert_eq!(visitor.discovered.len(), n);
+        assert_eq!(visitor.tree_edges.len(), n - 1);
+        assert!(visitor.back_edges.is_empty());
+        // 顶点0的自环与指回0的边是同一条边
+        assert_eq!(visitor.other_edges.len(), 2 * n - 1);
+        assert_eq!(visitor.other_edges[..3], [(0, 0), (1, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn test_visitor_adapter() {
+        let graph = sample_graph();
+
+        // 通过适配器使用原有的 CollectVisitor
+        let mut collect = CollectVisitor::default();
+        depth_first_visit(&graph, 0, &mut VisitorAdapter(&mut collect), 5);
+        assert_eq!(collect.order, vec![0, 1, 3, 4, 2]);
+    }
+
+    /// 发现目标顶点后终止遍历，并对指定顶点剪枝的访问器
+    struct SearchVisitor {
+        target: usize,
+        pruned: Option<usize>,
+        discovered: Vec<usize>,
+    }
+
+    impl TraversalVisitor for SearchVisitor {
+        fn discover_vertex(&mut self, vertex: usize) -> TraversalControl {
+            self.discovered.push(vertex);
+            if vertex == self.target {
+                TraversalControl::Stop
+            } else if Some(vertex) == self.pruned {
+                TraversalControl::Prune
+            } else {
+                TraversalControl::Continue
+            }
+        }
+    }
+
+    #[test]
+    fn test_early_termination() {
+        let graph = sample_graph();
+        let new_visitor = |target, pruned| SearchVisitor {
+            target,
+            pruned,
+            discovered: Vec::new(),
+        };
+
+        // BFS在发现目标2后立即停止，不再发现3和4
+        let mut visitor = new_visitor(2, None);
+        let control = breadth_first_visit(&graph, 0, &mut visitor, 5);
+        assert_eq!(control, TraversalControl::Stop);
+        assert_eq!(visitor.discovered, vec![0, 1, 2]);
+
+        let mut visitor = new_visitor(3, None);
+        let control = depth_first_visit(&graph, 0, &mut visitor, 5);
+        assert_eq!(control, TraversalControl::Stop);
+        assert_eq!(visitor.discovered, vec![0, 1, 3]);
+
+        let mut visitor = new_visitor(3, None);
+        let control = depth_first_visit_iterative(&graph, 0, &mut visitor, 5);
+        assert_eq!(control, TraversalControl::Stop);
+        assert_eq!(visitor.discovered, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_prune_branch() {
+        let graph = sample_graph();
+        let new_visitor = || SearchVisitor {
+            target: usize::MAX,
+            pruned: Some(1),
+            discovered: Vec::new(),
+        };
+
+        // 对顶点1剪枝后，只能经由1到达的3和4不会被发现
+        let mut visitor = new_visitor();
+        let control = breadth_first_visit(&graph, 0, &mut visitor, 5);
+        assert_eq!(control, TraversalControl::Continue);
+        assert_eq!(visitor.discovered, vec![0, 1, 2]);
+
+        let mut visitor = new_visitor();
+        depth_first_visit(&graph, 0, &mut visitor, 5);
+        assert_eq!(visitor.discovered, vec![0, 1, 2]);
+
+        let mut visitor = new_visitor();
+        depth_first_visit_iterative(&graph, 0, &mut visitor, 5);
+        assert_eq!(visitor.discovered, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_breadth_first_tree() {
+        let graph = sample_graph();
+
+        let tree = breadth_first_tree(&graph, 0, 5);
+        assert_eq!(tree.start, 0);
+        assert_eq!(tree.sources, vec![0]);
+        assert_eq!(
+            tree.distances,
+            vec![Some(0), Some(1), Some(1), Some(2), Some(2)]
+        );
+        assert_eq!(tree.parents, vec![None, Some(0), Some(0), Some(1), Some(1)]);
+        assert_eq!(tree.path_to(0), Some(vec![0]));
+        assert_eq!(tree.path_to(4), Some(vec![0, 1, 4]));
+
+        // 从3出发时0不可达
+        let tree = breadth_first_tree(&graph, 3, 5);
+        assert_eq!(
+            tree.distances,
+            vec![None, Some(3), Some(2), Some(0), Some(1)]
+        );
+        assert_eq!(tree.path_to(1), Some(vec![3, 4, 2, 1]));
+        assert_eq!(tree.path_to(0), None);
+    }
+
+    #[test]
+    fn test_visit_all_components() {
+        // 无向图（双向存储）: {0, 2}, {1, 3, 4}, {5}
+        let mut graph = AdjacencyList::<(), i32>::new(6);
+        for (u, v) in [(0, 2), (1, 3), (3, 4)] {
+            graph.add_edge(u, v, 1);
+            graph.add_edge(v, u, 1);
+        }
+
+        let mut visitor = EventVisitor::default();
+        let labels = breadth_first_visit_all(&graph, &mut visitor, 6);
+        assert_eq!(
+            labels,
+            vec![Some(0), Some(1), Some(0), Some(1), Some(1), Some(2)]
+        );
+        assert_eq!(visitor.components, vec![(0, 0), (1, 1), (2, 5)]);
+        assert_eq!(visitor.finished_components, vec![0, 1, 2]);
+        assert_eq!(visitor.discovered, vec![0, 2, 1, 3, 4, 5]);
+
+        let mut visitor = EventVisitor::default();
+        let labels = depth_first_visit_all(&graph, &mut visitor, 6);
+        assert_eq!(
+            labels,
+            vec![Some(0), Some(1), Some(0), Some(1), Some(1), Some(2)]
+        );
+        assert_eq!(visitor.components, vec![(0, 0), (1, 1), (2, 5)]);
+        assert_eq!(visitor.finished.len(), 6);
+
+        // 提前终止时，未被发现的顶点没有分量编号
+        let mut visitor = SearchVisitor {
+            target: 3,
+            pruned: None,
+            discovered: Vec::new(),
+        };
+        let labels = depth_first_visit_all(&graph, &mut visitor, 6);
+        assert_eq!(labels, vec![Some(0), Some(1), Some(0), Some(1), None, None]);
+    }
+
+    #[test]
+    fn test_multi_source_bfs() {
+        // 一条链 0 - 1 - 2 - 3 - 4 - 5 - 6（双向存储），设施位于0和5
+        let mut graph = AdjacencyList::<(), i32>::new(7);
+        for u in 0..6 {
+            graph.add_edge(u, u + 1, 1);
+            graph.add_edge(u + 1, u, 1);
+        }
+
+        let mut collect = CollectVisitor::default();
+        breadth_first_search_multi(&graph, &[0, 5], &mut collect, 7);
+        assert_eq!(collect.order, vec![0, 5, 1, 4, 6, 2, 3]);
+
+        let tree = breadth_first_tree_multi(&graph, &[0, 5, 0], 7);
+        assert_eq!(tree.start, 0);
+        assert_eq!(tree.sources, vec![0, 5]);
+        assert_eq!(
+            tree.distances,
+            vec![
+                Some(0),
+                Some(1),
+                Some(2),
+                Some(2),
+                Some(1),
+                Some(0),
+                Some(1)
+            ]
+        );
+        assert_eq!(
+            tree.source_of,
+            vec![
+                Some(0),
+                Some(0),
+                Some(0),
+                Some(5),
+                Some(5),
+                Some(5),
+                Some(5)
+            ]
+        );
+        assert_eq!(tree.path_to(3), Some(vec![5, 4, 3]));
+        assert_eq!(tree.path_to(2), Some(vec![0, 1, 2]));
+    }
+}
@@ -0,0 +1,291 @@
+//! 基于迭代器的惰性图遍历
+//!
+//! 与 [`traversal`](super::traversal) 中基于访问器的遍历不同，
+//! 这里的 `Bfs`、`Dfs` 实现了 `Iterator<Item = usize>`，每次调用 `next` 只前进一步，
+//! 可以直接与 `take_while`、`filter`、`zip` 等迭代器适配器组合使用。
+
+use std::collections::VecDeque;
+
+use super::traversal::GraphNeighbor;
+
+/// 广度优先遍历的迭代器
+///
+/// 迭代器自己持有访问标记和队列。调用 [`Bfs::move_to`] 可以从新的起点继续遍历，
+/// 已经访问过的顶点不会被重复产出，因此一个迭代器可以依次覆盖多个连通分量。
+///
+/// 产出顺序与 [`breadth_first_search`](super::traversal::breadth_first_search) 一致。
+#[derive(Debug, Clone)]
+pub struct Bfs<'a, G> {
+    /// 被遍历的图
+    graph: &'a G,
+    /// 访问标记数组
+    discovered: Vec<bool>,
+    /// 已发现但尚未产出的顶点
+    queue: VecDeque<usize>,
+}
+
+impl<'a, G> Bfs<'a, G>
+where
+    G: GraphNeighbor,
+{
+    /// 创建一个从 `start` 开始的广度优先遍历迭代器
+    ///
+    /// # 参数
+    /// * `graph` - 实现了 GraphNeighbor trait 的图结构
+    /// * `start` - 起始顶点索引
+    /// * `vertex_count` - 图中顶点的总数
+    pub fn new(graph: &'a G, start: usize, vertex_count: usize) -> Self {
+        let mut bfs = Bfs {
+            graph,
+            discovered: vec![false; vertex_count],
+            queue: VecDeque::new(),
+        };
+        bfs.move_to(start);
+        bfs
+    }
+
+    /// 丢弃尚未产出的顶点，从新的起点继续遍历，保留已产出顶点的访问标记
+    ///
+    /// 被丢弃的顶点恢复为未发现，之后仍可以从其他起点再次到达。
+    /// 如果 `start` 已经被访问过，迭代器将直接结束。
+    pub fn move_to(&mut self, start: usize) {
+        for dropped in self.queue.drain(..) {
+            self.discovered[dropped] = false;
+        }
+        if !self.discovered[start] {
+            self.discovered[start] = true;
+            self.queue.push_back(start);
+        }
+    }
+
+    /// 清空访问标记和队列，之后需要调用 [`Bfs::move_to`] 指定新的起点
+    pub fn reset(&mut self) {
+        self.discovered.fill(false);
+        self.queue.clear();
+    }
+
+    /// 判断顶点是否已经被发现
+    pub fn is_discovered(&self, vertex: usize) -> bool {
+        self.discovered[vertex]
+    }
+}
+
+impl<G> Iterator for Bfs<'_, G>
+where
+    G: GraphNeighbor,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let current = self.queue.pop_front()?;
+
+        // 出队时把所有未发现的邻接顶点入队
+        let mut neighbor = self.graph.first_neighbor(current);
+        while let Some(next) = neighbor {
+            if !self.discovered[next] {
+                self.discovered[next] = true;
+                self.queue.push_back(next);
+            }
+            neighbor = self.graph.next_neighbor(current, next);
+        }
+        Some(current)
+    }
+}
+
+/// 深度优先遍历（先序）的迭代器
+///
+/// 与 [`Bfs`] 一样自己持有访问标记和栈，并支持通过 [`Dfs::move_to`] 复用。
+/// 产出顺序与 [`depth_first_search`](super::traversal::depth_first_search) 一致。
+#[derive(Debug, Clone)]
+pub struct Dfs<'a, G> {
+    /// 被遍历的图
+    graph: &'a G,
+    /// 访问标记数组
+    discovered: Vec<bool>,
+    /// 栈中保存 (顶点, 下一个待检查的邻接顶点)
+    stack: Vec<(usize, Option<usize>)>,
+    /// 已发现但尚未产出的起点
+    pending: Option<usize>,
+}
+
+impl<'a, G> Dfs<'a, G>
+where
+    G: GraphNeighbor,
+{
+    /// 创建一个从 `start` 开始的深度优先遍历迭代器
+    ///
+    /// # 参数
+    /// * `graph` - 实现了 GraphNeighbor trait 的图结构
+    /// * `start` - 起始顶点索引
+    /// * `vertex_count` - 图中顶点的总数
+    pub fn new(graph: &'a G, start: usize, vertex_count: usize) -> Self {
+        let mut dfs = Dfs {
+            graph,
+            discovered: vec![false; vertex_count],
+            stack: Vec::new(),
+            pending: None,
+        };
+        dfs.move_to(start);
+        dfs
+    }
+
+    /// 丢弃尚未产出的起点，从新的起点继续遍历，保留已产出顶点的访问标记
+    ///
+    /// 栈中只有已经产出的顶点；尚未产出的起点恢复为未发现，之后仍可以再次到达。
+    /// 如果 `start` 已经被访问过，迭代器将直接结束。
+    pub fn move_to(&mut self, start: usize) {
+        self.stack.clear();
+        if let Some(dropped) = self.pending.take() {
+            self.discovered[dropped] = false;
+        }
+        if !self.discovered[start] {
+            self.discovered[start] = true;
+            self.pending = Some(start);
+        }
+    }
+
+    /// 清空访问标记和栈，之后需要调用 [`Dfs::move_to`] 指定新的起点
+    pub fn reset(&mut self) {
+        self.discovered.fill(false);
+        self.stack.clear();
+        self.pending = None;
+    }
+
+    /// 判断顶点是否已经被发现
+    pub fn is_discovered(&self, vertex: usize) -> bool {
+        self.discovered[vertex]
+    }
+}
+
+impl<G> Iterator for Dfs<'_, G>
+where
+    G: GraphNeighbor,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if let Some(start) = self.pending.take() {
+            self.stack.push((start, self.graph.first_neighbor(start)));
+            return Some(start);
+        }
+
+        while let Some(top) = self.stack.last_mut() {
+            let (current, neighbor) = *top;
+            match neighbor {
+                Some(next) => {
+                    // 先推进游标，遇到未发现的顶点就深入并产出它
+                    top.1 = self.graph.next_neighbor(current, next);
+                    if !self.discovered[next] {
+                        self.discovered[next] = true;
+                        self.stack.push((next, self.graph.first_neighbor(next)));
+                        return Some(next);
+                    }
+                }
+                // 当前顶点的邻接顶点都已检查完毕，回溯
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyMatrix;
+
+    #[test]
+    fn test_bfs_dfs_walkers() {
+        // 与BFS测试相同的有向图，另加一个孤立的边 5 → 6
+        //     0 → 1 → 3
+        //     ↓   ↓
+        //     2 → 4
+        let mut graph = AdjacencyMatrix::<String, i32>::new(7);
+        graph.add_edge(0, 1, Some(1));
+        graph.add_edge(0, 2, Some(1));
+        graph.add_edge(1, 3, Some(1));
+        graph.add_edge(1, 4, Some(1));
+        graph.add_edge(2, 4, Some(1));
+        graph.add_edge(5, 6, Some(1));
+
+        let order: Vec<usize> = Bfs::new(&graph, 0, 7).collect();
+        assert_eq!(order, vec![0, 1, 2, 3, 4]);
+
+        let order: Vec<usize> = Dfs::new(&graph, 0, 7).collect();
+        assert_eq!(order, vec![0, 1, 3, 4, 2]);
+
+        // 与迭代器适配器组合：找到3之前访问过的顶点
+        let before: Vec<usize> = Dfs::new(&graph, 0, 7).take_while(|&v| v != 3).collect();
+        assert_eq!(before, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_walker_reuse() {
+        let mut graph = AdjacencyMatrix::<String, i32>::new(5);
+        graph.add_edge(0, 1, Some(1));
+        graph.add_edge(2, 1, Some(1));
+        graph.add_edge(2, 3, Some(1));
+
+        // 依次从每个顶点出发，已访问过的顶点不会重复产出
+        let mut bfs = Bfs::new(&graph, 0, 5);
+        let mut components = vec![bfs.by_ref().collect::<Vec<_>>()];
+        for start in 1..5 {
+            bfs.move_to(start);
+            let component: Vec<usize> = bfs.by_ref().collect();
+            if !component.is_empty() {
+                components.push(component);
+            }
+        }
+        assert_eq!(components, vec![vec![0, 1], vec![2, 3], vec![4]]);
+
+        let mut dfs = Dfs::new(&graph, 2, 5);
+        assert_eq!(dfs.by_ref().collect::<Vec<_>>(), vec![2, 1, 3]);
+        assert!(dfs.is_discovered(1));
+        dfs.move_to(0);
+        assert_eq!(dfs.by_ref().collect::<Vec<_>>(), vec![0]);
+
+        // 重置后可以重新遍历
+        dfs.reset();
+        dfs.move_to(0);
+        assert_eq!(dfs.collect::<Vec<_>>(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_bfs_move_to_mid_iteration() {
+        let mut graph = AdjacencyMatrix::<String, i32>::new(4);
+        graph.add_edge(0, 1, Some(1));
+        graph.add_edge(0, 2, Some(1));
+        graph.add_edge(3, 2, Some(1));
+
+        // 产出0后队列中还有1、2，切换起点时它们被丢弃但不会丢失
+        let mut bfs = Bfs::new(&graph, 0, 4);
+        assert_eq!(bfs.next(), Some(0));
+        bfs.move_to(3);
+        assert!(!bfs.is_discovered(1));
+        let mut order = vec![0];
+        order.extend(bfs.by_ref());
+        for start in [1, 2] {
+            bfs.move_to(start);
+            order.extend(bfs.by_ref());
+        }
+        assert_eq!(order, vec![0, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_dfs_move_to_before_next() {
+        let mut graph = AdjacencyMatrix::<String, i32>::new(3);
+        graph.add_edge(2, 0, Some(1));
+
+        // 起点0还没有产出就切换到2，之后仍能从2到达0
+        let mut dfs = Dfs::new(&graph, 0, 3);
+        dfs.move_to(2);
+        assert!(!dfs.is_discovered(0));
+        assert_eq!(dfs.by_ref().collect::<Vec<_>>(), vec![2, 0]);
+
+        dfs.move_to(1);
+        dfs.move_to(1);
+        assert_eq!(dfs.collect::<Vec<_>>(), vec![1]);
+    }
+}
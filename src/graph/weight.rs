@@ -0,0 +1,69 @@
+//! 边权重相关的trait
+//!
+//! 最短路径、最小生成树等算法需要对边权重做加法和比较，
+//! 这里用 [`Weight`] 统一约束，整数和浮点数都已实现。
+
+use std::cmp::Ordering;
+use std::ops::Add;
+
+/// 可以参与路径长度计算的权重类型
+///
+/// 浮点数只实现了 `PartialOrd`，算法中遇到无法比较的值（NaN）时按相等处理，
+/// 因此调用者应保证权重中不含NaN。
+pub trait Weight: Copy + PartialOrd + Add<Output = Self> {
+    /// 加法单位元，即长度为0的路径的权重
+    fn zero() -> Self;
+}
+
+macro_rules! impl_weight {
+    ($($t:ty => $zero:expr),* $(,)?) => {
+        $(
+            impl Weight for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+        )*
+    };
+}
+
+impl_weight! {
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+    u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+    f32 => 0.0, f64 => 0.0,
+}
+
+/// 二叉堆中的元素，按权重从小到大出堆
+///
+/// `BinaryHeap` 是最大堆，这里把比较结果反转，得到以 `weight` 为键的最小堆。
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MinScored<D> {
+    /// 排序所用的权重
+    pub weight: D,
+    /// 对应的顶点
+    pub vertex: usize,
+}
+
+impl<D: PartialOrd> PartialEq for MinScored<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<D: PartialOrd> Eq for MinScored<D> {}
+
+impl<D: PartialOrd> PartialOrd for MinScored<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<D: PartialOrd> Ord for MinScored<D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .weight
+            .partial_cmp(&self.weight)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.vertex.cmp(&self.vertex))
+    }
+}